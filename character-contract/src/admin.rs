@@ -4,7 +4,8 @@ use game_common_module::constants::*;
 
 #[multiversx_sc::module]
 pub trait AdminModule: 
-    crate::storage::StorageModule {
+    crate::storage::StorageModule +
+    game_common_module::GameCommonModule {
 
     #[init]
     fn init(&self) {}
@@ -60,6 +61,72 @@ pub trait AdminModule:
         self.mint_citizen_seconds().set(mint_citizen_seconds);
     }
 
+    /// Set the cooldown in seconds between two recruitments of a citizen
+    #[only_owner]
+    #[endpoint(setRecruitCitizenCooldownSeconds)]
+    fn set_recruit_citizen_cooldown_seconds(&self, cooldown_seconds: u64) {
+        self.recruit_citizen_cooldown_seconds().set(cooldown_seconds);
+    }
+
+    /// Set the number of recruitments allowed for each citizen
+    #[only_owner]
+    #[endpoint(setMaxCitizenRecruitments)]
+    fn set_max_citizen_recruitments(&self, max_recruitments: u64) {
+        self.max_citizen_recruitments().set(max_recruitments);
+    }
+
+    /// Set the fee token and amount paid to rename a character and the treasury address receiving it, if not specified renaming is free
+    #[only_owner]
+    #[endpoint(setRenameFee)]
+    fn set_rename_fee(&self, rename_fee: OptionalValue<MultiValue3<TokenIdentifier, BigUint, ManagedAddress>>) {
+        match rename_fee {
+            OptionalValue::Some(rename_fee) => {
+                let (fee_token_id, fee_amount, treasury_address) = rename_fee.into_tuple();
+                require!(fee_amount > 0, "Rename fee must be greater than 0.");
+                self.rename_fee().set(EsdtTokenPayment::new(fee_token_id, 0, fee_amount));
+                self.rename_fee_treasury().set(treasury_address);
+            },
+            OptionalValue::None => {
+                self.rename_fee().clear();
+                self.rename_fee_treasury().clear();
+            },
+        }
+    }
+
+    /// Set the share of the resources spent on a character returned when retiring it, in basis points
+    #[only_owner]
+    #[endpoint(setRetireShare)]
+    fn set_retire_share(&self, retire_share: u64) {
+        require!(retire_share <= MAX_RETIRE_SHARE, "Retire share must be at most {}.", MAX_RETIRE_SHARE);
+        self.retire_share().set(retire_share);
+    }
+
+    /// Fund the retire reserve with WOOD, FOOD, GOLD or ORE tokens used to pay the retired characters
+    #[only_owner]
+    #[payable]
+    #[endpoint(fundRetireReserve)]
+    fn fund_retire_reserve(&self) {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), "Send WOOD, FOOD, GOLD or ORE tokens to fund the retire reserve.");
+
+        for payment in payments.iter() {
+            let token_id = &payment.token_identifier;
+            require!(self.is_required_token(token_id, &ManagedBuffer::from(WOOD_TICKER))
+                || self.is_required_token(token_id, &ManagedBuffer::from(FOOD_TICKER))
+                || self.is_required_token(token_id, &ManagedBuffer::from(GOLD_TICKER))
+                || self.is_required_token(token_id, &ManagedBuffer::from(ORE_TICKER)), "Only WOOD, FOOD, GOLD and ORE tokens can fund the retire reserve.");
+
+            self.add_to_reserve(self.retire_reserve(), &payment);
+        }
+    }
+
+    /// Set the game arena contract address allowed to add experience to characters
+    #[only_owner]
+    #[endpoint(setArenaContractAddress)]
+    fn set_arena_contract_address(&self, arena_contract_address: ManagedAddress) {
+        self.arena_contract_address().set(arena_contract_address);
+    }
+
     /// Set the tools NFT collection ID
     #[only_owner]
     #[endpoint(setToolsCollectionId)]
@@ -15,10 +15,11 @@ pub trait CharacterContract:
     admin::AdminModule +
     storage::StorageModule +
     game_common_module::GameCommonModule +
+    game_common_module::pending_mint::PendingMintModule +
     game_common_module::nft_attributes::NftAttributesModule
 {
 
-    /// Mints a Citizen NFT
+    /// Mints Citizen NFTs, one for each multiple of the Wood and Food required
     #[payable]
     #[endpoint(mintCitizen)]
     fn mint_citizen(&self, receiver_address: OptionalValue<ManagedAddress>) {
@@ -35,8 +36,13 @@ pub trait CharacterContract:
             if self.is_required_token(&token_id, &ManagedBuffer::from(FOOD_TICKER)) { food_amount = payment.amount.clone(); }         
         }
 
-        require!(wood_amount == MINT_CITIZEN_WOOD_QUANTITY, "Wood amount sent must be {}.", MINT_CITIZEN_WOOD_QUANTITY);
-        require!(food_amount == MINT_CITIZEN_FOOD_QUANTITY, "Food amount sent must be {}.", MINT_CITIZEN_FOOD_QUANTITY);
+        // Get the number of citizens to mint from the wood sent
+        let citizens_count = (&wood_amount / MINT_CITIZEN_WOOD_QUANTITY).to_u64().unwrap_or_default();
+        require!(citizens_count > 0 && wood_amount == BigUint::from(MINT_CITIZEN_WOOD_QUANTITY) * citizens_count,
+            "Wood amount sent must be a multiple of {}.", MINT_CITIZEN_WOOD_QUANTITY);
+        require!(citizens_count <= MAX_MINT_CITIZEN_COUNT, "Cannot mint more than {} citizens at once.", MAX_MINT_CITIZEN_COUNT);
+        let food_quantity = citizens_count * MINT_CITIZEN_FOOD_QUANTITY;
+        require!(food_amount == food_quantity, "Food amount sent must be {} for {} citizen(s).", food_quantity, citizens_count);
 
         // Determine the receiver address if one is specified
         let user = match receiver_address {
@@ -44,23 +50,34 @@ pub trait CharacterContract:
                 OptionalValue::None => self.blockchain().get_caller(),
             };
 
+        // Hold the wood and food for one citizen in escrow until claim
+        let mut citizen_payments = ManagedVec::new();
+        for payment in payments.iter() {
+            citizen_payments.push(EsdtTokenPayment::new(payment.token_identifier.clone(), 0, &payment.amount / citizens_count));
+        }
+
         // Register the NFT mint to the receiver address
+        self.migrate_legacy_citizens_to_mint(&user);
         let mut user_citizens_to_mint = self.citizens_to_mint(&user);
-        // Record the current mint start timestamp
-        let mint_start_timestamp = self.blockchain().get_block_timestamp();
-        // Add the mint start timestamp to the mint user's list
-        user_citizens_to_mint.push(&mint_start_timestamp);
-        
-        // Burn the wood and food sent
-        for payment in payments.iter() {
-            self.send().esdt_local_burn(&payment.token_identifier, 0, &payment.amount);
+        // Record the current mint start timestamp and the escrowed payments for each citizen
+        let pending_mint = PendingMint {
+            start_timestamp: self.blockchain().get_block_timestamp(),
+            payments: citizen_payments,
+        };
+        for _ in 0..citizens_count {
+            user_citizens_to_mint.push(&pending_mint);
         }
     }
 
 
-    /// Claims a Citizen NFT if the minting period is over
+    /// Claims the Citizen NFTs whose minting period is over
+    ///
+    /// # Arguments
+    /// * `receiver_address` - Receiver address optional, if not specified the caller address will be used
+    /// * `max_count` - Maximum number of citizens to claim optional, to keep large claims within the gas limit
+    #[allow_multiple_var_args]
     #[endpoint(claimCitizen)]
-    fn claim_citizen(&self, receiver_address: OptionalValue<ManagedAddress>) {
+    fn claim_citizen(&self, receiver_address: OptionalValue<ManagedAddress>, max_count: OptionalValue<usize>) {
 
         // Determine the receiver address if one is specified
         let user = match receiver_address {
@@ -69,29 +86,42 @@ pub trait CharacterContract:
         };
 
         // Check if the user has any NFTs to mint
-        let user_citizens = self.citizens_to_mint(&user);
+        self.migrate_legacy_citizens_to_mint(&user);
+        let mut user_citizens = self.citizens_to_mint(&user);
         let citizens_pending_count = user_citizens.len();
 
         // Exit with an error if the user has no NFTs to mint
         require!(citizens_pending_count > 0, "No citizens pending to be minted.");
 
+        let max_count = max_count.into_option().unwrap_or(citizens_pending_count);
+        require!(max_count > 0, "Max count must be greater than 0.");
+
         let mut citizens_minted = 0;
-        let mut still_minting : ManagedVec<u64> = ManagedVec::new();
 
         let mint_citizen_seconds = if self.mint_citizen_seconds().is_empty() { MINT_CITIZEN_SECONDS_DEFAULT }
             else { self.mint_citizen_seconds().get() };
 
-        // Find mintable citizens
-        for timestamp in user_citizens.iter() {
-        
-            // Check if the minting period has elapsed
-            if self.blockchain().get_block_timestamp() - timestamp < mint_citizen_seconds {
-                still_minting.push(timestamp);
+        // Find mintable citizens until the max count is reached (VecMapper indexes start from 1)
+        // The pending mints left are moved up in place, keeping their order for the indexes used to cancel or speed up
+        let mut citizens_left = 0;
+        for index in 1..=citizens_pending_count {
+            // Keep the pending mint if the max count is reached or the minting period has not elapsed
+            let pending_mint = user_citizens.get(index);
+            if citizens_minted >= max_count ||
+                self.blockchain().get_block_timestamp() - pending_mint.start_timestamp < mint_citizen_seconds
+            {
+                citizens_left += 1;
+                if citizens_left != index {
+                    user_citizens.set(citizens_left, &pending_mint);
+                }
                 continue;
             }
 
+            // Burn the escrowed wood and food
+            self.burn_pending_mint_payments(&pending_mint);
+
             // Mint the NFT
-            let nft_nonce = self.create_citizen_nft();
+            let nft_nonce = self.create_citizen_nft(CHARACTER_ORIGIN_MINTED);
 
             citizens_minted += 1;
 
@@ -104,26 +134,175 @@ pub trait CharacterContract:
             );
         }
 
-        // If any citizens were minted
-        if citizens_minted > 0 {
-            // Update the user's mint list
-            self.citizens_to_mint(&user).clear();
-            if !still_minting.is_empty() {
-                for timestamp in still_minting.iter() {
-                    self.citizens_to_mint(&user).push(&timestamp);
-                }
+        require!(citizens_minted > 0, "No citizen to mint. {} citizen(s) still in the minting period.", citizens_pending_count);
+
+        // Remove the entries of the claimed pending mints, left at the end of the list
+        for _ in citizens_left..citizens_pending_count {
+            user_citizens.swap_remove(user_citizens.len());
+        }
+    }
+
+
+    /// Cancels a pending citizen mint of the caller and refunds the escrowed wood and food minus the cancel fee
+    ///
+    /// # Arguments
+    /// * `index` - Index of the pending mint in the caller's list, starting from 0
+    #[endpoint(cancelPendingMint)]
+    fn cancel_pending_mint(&self, index: usize) {
+        let user = self.blockchain().get_caller();
+
+        self.migrate_legacy_citizens_to_mint(&user);
+        let mut user_citizens = self.citizens_to_mint(&user);
+        let citizens_pending_count = user_citizens.len();
+        require!(index < citizens_pending_count, "No pending citizen mint at index {}. {} citizen(s) pending.", index, citizens_pending_count);
+
+        // Remove the pending mint keeping the order of the remaining ones (VecMapper indexes start from 1)
+        let pending_mint = user_citizens.get(index + 1);
+        require!(!pending_mint.payments.is_empty(), "Citizen at index {} was paid before the escrow and cannot be cancelled.", index);
+        for next_index in index + 2..=citizens_pending_count {
+            user_citizens.set(next_index - 1, &user_citizens.get(next_index));
+        }
+        user_citizens.swap_remove(citizens_pending_count);
+
+        // Refund the escrowed wood and food minus the cancel fee
+        self.refund_pending_mint_payments(&user, &pending_mint, self.get_cancel_mint_fee());
+    }
+
+
+    /// Speeds up a pending citizen mint of the caller by paying the speed-up price for each second saved
+    /// The payment is burned, or sent to the treasury if one is set
+    ///
+    /// # Arguments
+    /// * `index` - Index of the pending mint in the caller's list, starting from 0
+    /// * `seconds` - Seconds to cut from the remaining wait optional, if not specified the mint is finished instantly
+    #[payable]
+    #[endpoint(speedUpPendingMint)]
+    fn speed_up_pending_mint(&self, index: usize, seconds: OptionalValue<u64>) {
+        require!(!self.speed_up_price().is_empty(), "Speed-up price not set.");
+
+        let user = self.blockchain().get_caller();
+
+        self.migrate_legacy_citizens_to_mint(&user);
+        let mut user_citizens = self.citizens_to_mint(&user);
+        let citizens_pending_count = user_citizens.len();
+        require!(index < citizens_pending_count, "No pending citizen mint at index {}. {} citizen(s) pending.", index, citizens_pending_count);
+
+        // Get the remaining wait of the pending mint (VecMapper indexes start from 1)
+        let mut pending_mint = user_citizens.get(index + 1);
+        let remaining_seconds = self.get_citizen_mint_remaining_seconds(&pending_mint);
+        require!(remaining_seconds > 0, "Citizen at index {} is ready to be claimed.", index);
+
+        // Pay for the seconds cut from the remaining wait
+        let seconds_saved = self.pay_speed_up(remaining_seconds, seconds);
+
+        // Move the mint start timestamp back by the seconds saved
+        pending_mint.start_timestamp -= seconds_saved;
+        user_citizens.set(index + 1, &pending_mint);
+
+    }
+
+    /// Returns the cost to finish a pending citizen mint of the user instantly, as the speed-up token and amount
+    ///
+    /// # Arguments
+    /// * `user` - User address
+    /// * `index` - Index of the pending mint in the user's list, starting from 0
+    #[view(getSpeedUpCost)]
+    fn get_speed_up_cost(&self, user: ManagedAddress, index: usize) -> EsdtTokenPayment {
+        require!(!self.speed_up_price().is_empty(), "Speed-up price not set.");
+
+        let user_citizens = self.citizens_to_mint(&user);
+        let citizens_pending_count = user_citizens.len();
+        require!(index < citizens_pending_count, "No pending citizen mint at index {}. {} citizen(s) pending.", index, citizens_pending_count);
+
+        let remaining_seconds = self.get_citizen_mint_remaining_seconds(&user_citizens.get(index + 1));
+
+        self.get_speed_up_payment(remaining_seconds)
+    }
+
+
+    /// Recruits a new Citizen NFT with two Citizen NFTs, for a smaller Wood and Food cost than minting
+    /// Each citizen has a limited number of recruitments and a cooldown between them
+    ///
+    /// # Arguments
+    /// * `owner_address` - Citizen NFTs owner address, receiving the citizens back with the recruited one
+    #[payable]
+    #[endpoint(recruitCitizen)]
+    fn recruit_citizen(&self, owner_address: ManagedAddress) {
+        self.require_character_collection();
+
+        let transfers = self.call_value().all_esdt_transfers();
+        require!(transfers.len() == 4, "Endpoint requires 4 transfers, 2 Citizen NFTs, Wood and Food.");
+
+        let collection_id = self.characters_nft_collection().get_token_id();
+        let mut citizen_nft_nonces : ManagedVec<u64> = ManagedVec::new();
+        let mut wood_amount = BigUint::zero();
+        let mut food_amount = BigUint::zero();
+
+        // Check the citizens, wood and food required
+        for transfer in transfers.iter() {
+            let token_id = &transfer.token_identifier;
+            if *token_id == collection_id { citizen_nft_nonces.push(transfer.token_nonce); }
+            if self.is_required_token(token_id, &ManagedBuffer::from(WOOD_TICKER)) { wood_amount = transfer.amount.clone(); }
+            if self.is_required_token(token_id, &ManagedBuffer::from(FOOD_TICKER)) { food_amount = transfer.amount.clone(); }
+        }
+
+        require!(citizen_nft_nonces.len() == 2, "Endpoint requires 2 Citizen NFTs.");
+        require!(citizen_nft_nonces.get(0) != citizen_nft_nonces.get(1), "Citizen NFTs must be different.");
+        require!(wood_amount == RECRUIT_CITIZEN_WOOD_QUANTITY, "Wood amount must be {}.", RECRUIT_CITIZEN_WOOD_QUANTITY);
+        require!(food_amount == RECRUIT_CITIZEN_FOOD_QUANTITY, "Food amount must be {}.", RECRUIT_CITIZEN_FOOD_QUANTITY);
+
+        let max_recruitments = self.get_max_citizen_recruitments();
+        let cooldown_seconds = self.get_recruit_citizen_cooldown_seconds();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+
+        // Check and record the recruitment for each citizen, the NFTs are held by the SC
+        for citizen_nft_nonce in citizen_nft_nonces.iter() {
+            let character = self.get_character(&self.blockchain().get_sc_address(), &collection_id, citizen_nft_nonce);
+            require!(character.is_citizen(), "Character nonce {} is not a citizen.", citizen_nft_nonce);
+
+            let recruitments = self.citizen_recruitments(citizen_nft_nonce).get();
+            require!(recruitments < max_recruitments, "Citizen nonce {} reached the limit of {} recruitments.", citizen_nft_nonce, max_recruitments);
+
+            let last_recruitment = self.citizen_last_recruitment(citizen_nft_nonce).get();
+            let next_recruitment = last_recruitment + cooldown_seconds;
+            require!(recruitments == 0 || current_timestamp >= next_recruitment,
+                "Citizen nonce {} can recruit again at timestamp {}.", citizen_nft_nonce, next_recruitment);
+
+            self.citizen_recruitments(citizen_nft_nonce).set(recruitments + 1);
+            self.citizen_last_recruitment(citizen_nft_nonce).set(current_timestamp);
+        }
+
+        // Burn the wood and food sent
+        for transfer in transfers.iter() {
+            if transfer.token_identifier != collection_id {
+                self.send().esdt_local_burn(&transfer.token_identifier, 0, &transfer.amount);
             }
         }
-        else {
-            sc_panic!("No citizen to mint. {} citizen(s) still in the minting period.", citizens_pending_count);
+
+        // Mint the recruited citizen, its origin keeps the retire refund to a share of the recruitment cost
+        let recruited_nft_nonce = self.create_citizen_nft(CHARACTER_ORIGIN_RECRUITED);
+
+        // Send the citizens back to the owner with the recruited one
+        let mut citizens = ManagedVec::new();
+        for citizen_nft_nonce in citizen_nft_nonces.iter() {
+            citizens.push(EsdtTokenPayment::new(collection_id.clone(), citizen_nft_nonce, BigUint::from(1u64)));
         }
+        citizens.push(EsdtTokenPayment::new(collection_id, recruited_nft_nonce, BigUint::from(1u64)));
+        self.send().direct_multi(&owner_address, &citizens);
     }
 
 
+    /// Upgrades a Citizen NFT to a Soldier NFT of the chosen class
+    ///
+    /// # Arguments
+    /// * `citizen_nft_nonce` - Citizen NFT nonce
+    /// * `class` - Soldier class (1 Infantry, 2 Archer, 3 Cavalry)
+    /// * `owner_address` - Citizen NFT owner address
     #[payable]
     #[endpoint(upgradeCitizenToSoldier)]
-    fn upgrade_citizen_to_soldier(&self, citizen_nft_nonce: u64, owner_address: ManagedAddress) {
+    fn upgrade_citizen_to_soldier(&self, citizen_nft_nonce: u64, class: u8, owner_address: ManagedAddress) {
         self.require_character_collection();
+        require!(Character::is_valid_class(class), "Invalid class {}.", class);
 
         let payments = self.call_value().all_esdt_transfers();
         require!(payments.len() == 2, "Endpoint requires 2 payment tokens, Gold and Ore.");
@@ -143,7 +322,7 @@ pub trait CharacterContract:
         require!(ore_amount == CITIZEN_TO_SOLDIER_ORE_QUANTITY, "Ore amount must be {}.", CITIZEN_TO_SOLDIER_ORE_QUANTITY);
         
         // Upgrade the NFT
-        self.upgrade_citizen_to_soldier_nft(citizen_nft_nonce, owner_address);
+        self.upgrade_citizen_to_soldier_nft(citizen_nft_nonce, class, owner_address);
 
         // Burn the gold and ore sent
         for payment in payments.iter() {
@@ -152,7 +331,8 @@ pub trait CharacterContract:
 
     }
 
-    /// Upgrades a Soldier NFT with a Tool NFT
+    /// Upgrades a Soldier NFT with a Tool NFT by equipping the tool in its slot
+    /// A character has one slot for each tool type (one shield and one sword)
     #[payable]
     #[endpoint(upgradeSoldier)]
     fn upgrade_soldier(&self, owner_address: ManagedAddress) {
@@ -198,14 +378,304 @@ pub trait CharacterContract:
 
     }
 
+    /// Unequips a Tool NFT from a Soldier NFT, removing the tool stats
+    /// The Soldier NFT and the Tool NFT are sent to the owner
+    ///
+    /// # Arguments
+    /// * `tool_type` - Tool type of the slot to unequip (1 shield, 2 sword)
+    /// * `owner_address` - Character NFT owner address
+    #[payable]
+    #[endpoint(unequipTool)]
+    fn unequip_tool(&self, tool_type: u8, owner_address: ManagedAddress) {
+        self.require_character_collection();
+        self.require_tools_collection();
+
+        let payment = self.call_value().single_esdt();
+        let character_nft_nonce = payment.token_nonce;
+        self.characters_nft_collection().require_same_token(&payment.token_identifier);
+
+        // Get the equipped tool
+        let tool_nft_nonce = match self.equipped_tools(character_nft_nonce).remove(&tool_type) {
+            Some(tool_nft_nonce) => tool_nft_nonce,
+            None => sc_panic!("No tool equipped in slot {}.", tool_type),
+        };
+
+        // The owner address is the SC address since the NFTs are held by the SC
+        let sc_address = self.blockchain().get_sc_address();
+        let tools_collection_id = self.tools_nft_collection().get();
+
+        // Remove the tool stats from the character
+        let mut soldier = self.get_character(&sc_address, &self.characters_nft_collection().get_token_id(), character_nft_nonce);
+        let tool = self.get_tool(&sc_address, &tools_collection_id, tool_nft_nonce);
+        soldier.downgrade(&tool);
+
+        self.recreate_character_nft(character_nft_nonce, &soldier);
+
+        // Send the soldier NFT and the tool NFT back to the owner
+        let mut transfers = ManagedVec::new();
+        transfers.push(EsdtTokenPayment::new(self.characters_nft_collection().get_token_id(), character_nft_nonce, BigUint::from(1u64)));
+        transfers.push(EsdtTokenPayment::new(tools_collection_id, tool_nft_nonce, BigUint::from(1u64)));
+        self.send().direct_multi(&owner_address, &transfers);
+    }
+
+    /// Adds experience points to Soldier NFTs after an arena battle
+    /// Only the game arena contract can call this endpoint
+    ///
+    /// # Arguments
+    /// * `owner_address` - Character NFTs owner address
+    /// * `characters_xp` - Character NFT nonces with the experience points to add
+    #[endpoint(addExperience)]
+    fn add_experience(&self, owner_address: ManagedAddress, characters_xp: MultiValueEncoded<MultiValue2<u64, u64>>) {
+        self.require_character_collection();
+        require!(!self.arena_contract_address().is_empty(), "Arena contract address not set");
+        require!(self.blockchain().get_caller() == self.arena_contract_address().get(), "Only the arena contract can add experience");
+
+        for character_xp in characters_xp.into_iter() {
+            let (character_nft_nonce, xp) = character_xp.into_tuple();
+
+            // Add the experience points to the character
+            let mut character = self.get_character(&owner_address, &self.characters_nft_collection().get_token_id(), character_nft_nonce);
+            character.add_xp(xp);
+
+            self.recreate_character_nft(character_nft_nonce, &character);
+        }
+    }
+
+    /// Levels up a Soldier NFT with enough experience points, raising its base attack or defence
+    ///
+    /// # Arguments
+    /// * `stat` - Stat to raise (1 attack, 2 defence)
+    /// * `owner_address` - Character NFT owner address
+    #[payable]
+    #[endpoint(levelUp)]
+    fn level_up(&self, stat: u8, owner_address: ManagedAddress) {
+        self.require_character_collection();
+        require!(stat == LEVEL_UP_STAT_ATTACK || stat == LEVEL_UP_STAT_DEFENCE, "Invalid stat {}.", stat);
+
+        let payment = self.call_value().single_esdt();
+        let character_nft_nonce = payment.token_nonce;
+        self.characters_nft_collection().require_same_token(&payment.token_identifier);
+
+        // Get the character, the NFT is held by the SC
+        let mut character = self.get_character(&self.blockchain().get_sc_address(), &self.characters_nft_collection().get_token_id(), character_nft_nonce);
+        require!(character.is_military(), "Character is not a soldier");
+
+        // Check the experience points
+        let next_level_xp = character.next_level_xp();
+        require!(character.can_level_up(), "Not enough experience. {} XP needed for the next level.", next_level_xp);
+
+        // Level up the character
+        require!(character.level_up(stat), "Stat cannot be raised above {}.", MAX_CHARACTER_STAT);
+        self.recreate_character_nft(character_nft_nonce, &character);
+
+        // Send the character NFT back to the owner
+        self.send().direct_esdt(
+            &owner_address,
+            &self.characters_nft_collection().get_token_id(),
+            character_nft_nonce,
+            &BigUint::from(1u64),
+        );
+    }
+
+    /// Promotes a Soldier or higher rank NFT to the next rank (Knight, Captain, General)
+    /// The character must meet the attack and defence thresholds of the next rank
+    ///
+    /// # Arguments
+    /// * `character_nft_nonce` - Character NFT nonce
+    /// * `owner_address` - Character NFT owner address
+    #[payable]
+    #[endpoint(promote)]
+    fn promote(&self, character_nft_nonce: u64, owner_address: ManagedAddress) {
+        self.require_character_collection();
+
+        // Get the character
+        let mut character = self.get_character(&owner_address, &self.characters_nft_collection().get_token_id(), character_nft_nonce);
+        require!(character.is_military(), "Character is not a soldier");
+
+        // Get the next rank requirements
+        let next_rank = character.rank + 1;
+        let requirements = match PromotionRequirements::for_rank(next_rank) {
+            Some(requirements) => requirements,
+            None => sc_panic!("Character rank {} cannot be promoted.", character.rank),
+        };
+
+        // Check the stat thresholds on the base stats, so the rank is kept after unequipping the tools
+        let (base_attack, base_defence) = self.get_base_stats(character_nft_nonce, &character);
+        let min_attack = requirements.min_attack;
+        let min_defence = requirements.min_defence;
+        require!(base_attack >= min_attack, "Base attack must be at least {} for the promotion.", min_attack);
+        require!(base_defence >= min_defence, "Base defence must be at least {} for the promotion.", min_defence);
+
+        let payments = self.call_value().all_esdt_transfers();
+        require!(payments.len() == 2, "Endpoint requires 2 payment tokens, Gold and Ore.");
+
+        let mut gold_amount = BigUint::zero();
+        let mut ore_amount = BigUint::zero();
+
+        // Check the gold and ore required
+        for payment in payments.iter() {
+            let token_id = &payment.token_identifier;
+
+            if self.is_required_token(token_id, &ManagedBuffer::from(GOLD_TICKER)) { gold_amount = payment.amount.clone(); }
+            if self.is_required_token(token_id, &ManagedBuffer::from(ORE_TICKER)) { ore_amount = payment.amount.clone(); }
+        }
+
+        let gold_quantity = requirements.gold_quantity;
+        let ore_quantity = requirements.ore_quantity;
+        require!(gold_amount == gold_quantity, "Gold amount must be {}.", gold_quantity);
+        require!(ore_amount == ore_quantity, "Ore amount must be {}.", ore_quantity);
+
+        // Promote the NFT
+        character.promote();
+        self.recreate_character_nft(character_nft_nonce, &character);
+
+        // Burn the gold and ore sent
+        for payment in payments.iter() {
+            self.send().esdt_local_burn(&payment.token_identifier, 0, &payment.amount);
+        }
+    }
+
+    /// Renames a Character NFT held by the caller
+    /// The name must be unique (case insensitive) and is shown in the NFT name after the rank (e.g., Knight Arthur)
+    ///
+    /// # Arguments
+    /// * `character_nft_nonce` - Character NFT nonce
+    /// * `name` - New character name, letters and digits separated by single spaces
+    #[payable]
+    #[endpoint(renameCharacter)]
+    fn rename_character(&self, character_nft_nonce: u64, name: ManagedBuffer) {
+        self.require_character_collection();
+
+        // Get the character, the NFT must be held by the caller
+        let caller = self.blockchain().get_caller();
+        let character = self.get_character(&caller, &self.characters_nft_collection().get_token_id(), character_nft_nonce);
+
+        // Check the name and its uniqueness
+        let name_key = self.get_character_name_key(&name);
+        let name_index = self.character_name_index(&name_key);
+        require!(name_index.is_empty() || name_index.get() == character_nft_nonce, "Name {} is already taken.", name);
+
+        // Check the rename fee if one is set
+        let payments = self.call_value().all_esdt_transfers();
+        if self.rename_fee().is_empty() {
+            require!(payments.is_empty(), "Renaming a character is free, no payment required.");
+        } else {
+            let rename_fee = self.rename_fee().get();
+            let fee_token_id = rename_fee.token_identifier;
+            let fee_amount = rename_fee.amount;
+            require!(payments.len() == 1, "Renaming a character costs {} {}.", fee_amount, fee_token_id);
+            let payment = payments.get(0);
+            require!(payment.token_identifier == fee_token_id && payment.amount == fee_amount,
+                "Renaming a character costs {} {}.", fee_amount, fee_token_id);
+
+            // Send the fee to the treasury
+            self.send().direct_esdt(&self.rename_fee_treasury().get(), &fee_token_id, 0, &fee_amount);
+        }
+
+        // Release the previous name and register the new one
+        if !self.character_name(character_nft_nonce).is_empty() {
+            let previous_name_key = self.get_character_name_key(&self.character_name(character_nft_nonce).get());
+            self.character_name_index(&previous_name_key).clear();
+        }
+        self.character_name_index(&name_key).set(character_nft_nonce);
+        self.character_name(character_nft_nonce).set(&name);
+
+        // Recreate the NFT with the new name
+        self.recreate_character_nft(character_nft_nonce, &character);
+    }
+
+    /// Returns the Character NFT nonce with the given name (case insensitive), if any
+    ///
+    /// # Arguments
+    /// * `name` - Character name
+    #[view(getCharacterByName)]
+    fn get_character_by_name(&self, name: ManagedBuffer) -> OptionalValue<u64> {
+        let name_key = self.get_character_name_key(&name);
+        let name_index = self.character_name_index(&name_key);
+
+        if name_index.is_empty() { OptionalValue::None }
+        else { OptionalValue::Some(name_index.get()) }
+    }
+
+    /// Retires a Character NFT by burning it and returning a share of the resources spent on it
+    /// The equipped tools are sent back to the owner
+    ///
+    /// # Arguments
+    /// * `owner_address` - Character NFT owner address
+    #[payable]
+    #[endpoint(retireCharacter)]
+    fn retire_character(&self, owner_address: ManagedAddress) {
+        self.require_character_collection();
+
+        let payment = self.call_value().single_esdt();
+        let character_nft_nonce = payment.token_nonce;
+        self.characters_nft_collection().require_same_token(&payment.token_identifier);
+
+        // Get the character, the NFT is held by the SC
+        let character = self.get_character(&self.blockchain().get_sc_address(), &payment.token_identifier, character_nft_nonce);
+
+        // Get the share of the resources spent on the character
+        let retire_share = self.get_retire_share();
+        let cost = character.resources_spent();
+        let resources = [
+            (WOOD_TICKER, cost.wood_quantity),
+            (FOOD_TICKER, cost.food_quantity),
+            (GOLD_TICKER, cost.gold_quantity),
+            (ORE_TICKER, cost.ore_quantity),
+        ];
+
+        // Take the returned resources from the retire reserve
+        let mut transfers = ManagedVec::new();
+        for (token_ticker, quantity) in resources.iter() {
+            let amount = BigUint::from(quantity * retire_share / MAX_RETIRE_SHARE);
+            if amount > 0 { transfers.push(self.take_from_reserve(self.retire_reserve(), "retire", token_ticker, amount)); }
+        }
+
+        // Unequip the tools
+        for (_, tool_nft_nonce) in self.equipped_tools(character_nft_nonce).iter() {
+            transfers.push(EsdtTokenPayment::new(self.tools_nft_collection().get(), tool_nft_nonce, BigUint::from(1u64)));
+        }
+        self.equipped_tools(character_nft_nonce).clear();
+
+        // Release the character name
+        if !self.character_name(character_nft_nonce).is_empty() {
+            let name_key = self.get_character_name_key(&self.character_name(character_nft_nonce).get());
+            self.character_name_index(&name_key).clear();
+            self.character_name(character_nft_nonce).clear();
+        }
+
+        // Burn the character NFT
+        self.send().esdt_local_burn(&payment.token_identifier, character_nft_nonce, &payment.amount);
+
+        // Send the resources and the tools to the owner
+        if !transfers.is_empty() {
+            self.send().direct_multi(&owner_address, &transfers);
+        }
+    }
+
     // Private functions
 
-    /// Creates a Citizen NFT
-    fn create_citizen_nft(&self) -> u64 {
+    /// Move the citizen mints queued before the escrow to the citizens to mint of the user
+    /// Their wood and food were burned on mint, so they have no escrowed payments
+    fn migrate_legacy_citizens_to_mint(&self, user: &ManagedAddress) {
+        let mut legacy_citizens_to_mint = self.legacy_citizens_to_mint(user);
+        if legacy_citizens_to_mint.is_empty() {
+            return;
+        }
+
+        let mut user_citizens_to_mint = self.citizens_to_mint(user);
+        for start_timestamp in legacy_citizens_to_mint.iter() {
+            user_citizens_to_mint.push(&PendingMint { start_timestamp, payments: ManagedVec::new() });
+        }
+        legacy_citizens_to_mint.clear();
+    }
+
+    /// Creates a Citizen NFT obtained with the given origin (minted or recruited)
+    fn create_citizen_nft(&self, origin: u8) -> u64 {
         self.require_character_collection();
 
         // Create new citizen character
-        let new_citizen = Character::new_citizen();
+        let new_citizen = Character::new_citizen(origin);
 
         // Get the last minted NFT nonce
         let last_minted_nft_nonce = 
@@ -253,7 +723,7 @@ pub trait CharacterContract:
     }
 
     /// Upgrades a Citizen NFT to a Soldier NFT
-    fn upgrade_citizen_to_soldier_nft(&self, citizen_nft_nonce: u64, owner_address: ManagedAddress) {
+    fn upgrade_citizen_to_soldier_nft(&self, citizen_nft_nonce: u64, class: u8, owner_address: ManagedAddress) {
         self.require_character_collection();
 
         // Get character
@@ -263,12 +733,10 @@ pub trait CharacterContract:
         require!(character.is_citizen(), "Character is not a citizen");
 
         // Create new soldier character
-        let soldier = Character::new_soldier();
+        let soldier = Character::new_soldier(class, character.origin);
 
         // Create new NFT name
-        let new_nft_name = sc_format!("{} {}",
-            ManagedBuffer::from(SOLDIER_NFT_NAME.as_bytes()),
-            citizen_nft_nonce);
+        let new_nft_name = self.get_character_nft_name(citizen_nft_nonce, &soldier);
 
         // Set the royalties
         let royalties = BigUint::from(CHARACTER_NFT_ROYALTIES);
@@ -311,8 +779,8 @@ pub trait CharacterContract:
         // Get the character
         let character = self.get_character(&owner_address, &self.characters_nft_collection().get_token_id(), character_nft_nonce);
 
-        // Check if the character is a soldier
-        require!(character.is_soldier(), "Character is not a soldier");
+        // Check if the character is a soldier or a higher rank
+        require!(character.is_military(), "Character is not a soldier");
 
         // Character is an upgradable soldier
         let mut soldier = character;
@@ -320,21 +788,32 @@ pub trait CharacterContract:
         // Get the tool
         let tool = self.get_tool(&owner_address, &self.tools_nft_collection().get(), tool_nft_nonce);
 
+        // Equip the tool in its slot
+        let tool_type = tool.tool_type;
+        let mut equipped_tools = self.equipped_tools(character_nft_nonce);
+        require!(!equipped_tools.contains_key(&tool_type), "A tool is already equipped in slot {}.", tool_type);
+        require!(!self.has_unrecorded_tool(character_nft_nonce, &soldier, &tool),
+            "Soldier has a tool upgraded before the equipment slots in slot {}.", tool_type);
+        equipped_tools.insert(tool_type, tool_nft_nonce);
+
         // Upgrade the soldier
-        soldier.upgrade(&tool);
+        require!(soldier.upgrade(&tool), "Tool cannot raise the soldier stats above {}.", MAX_CHARACTER_STAT);
+
+        self.recreate_character_nft(character_nft_nonce, &soldier);
+    }
 
+    /// Recreates a Character NFT with the name, attributes and URIs of the given character
+    fn recreate_character_nft(&self, character_nft_nonce: u64, character: &Character) {
         // NFT name
-        let new_nft_name = sc_format!("{} {}",
-            ManagedBuffer::from(SOLDIER_NFT_NAME.as_bytes()),
-            character_nft_nonce);
+        let new_nft_name = self.get_character_nft_name(character_nft_nonce, character);
 
         // Set the royalties
         let royalties = BigUint::from(CHARACTER_NFT_ROYALTIES);
 
         // Get new NFT attributes
-        let new_attributes = self.get_nft_attributes(&soldier);
+        let new_attributes = self.get_nft_attributes(character);
         // Get new NFT URIs
-        let new_uris = self.get_nft_asset_uris(&soldier);
+        let new_uris = self.get_nft_asset_uris(character);
 
         // Calculate new NFT attributes hash
         let new_attributes_sha256 = self.crypto().sha256(&new_attributes);
@@ -358,6 +837,87 @@ pub trait CharacterContract:
         tx.sync_call();
     }
 
+    /// Get the retire share in basis points
+    fn get_retire_share(&self) -> u64 {
+        if self.retire_share().is_empty() { RETIRE_SHARE_DEFAULT }
+        else { self.retire_share().get() }
+    }
+
+    /// Get the number of recruitments allowed for each citizen
+    fn get_max_citizen_recruitments(&self) -> u64 {
+        if self.max_citizen_recruitments().is_empty() { MAX_CITIZEN_RECRUITMENTS_DEFAULT }
+        else { self.max_citizen_recruitments().get() }
+    }
+
+    /// Get the cooldown in seconds between two recruitments of a citizen
+    fn get_recruit_citizen_cooldown_seconds(&self) -> u64 {
+        if self.recruit_citizen_cooldown_seconds().is_empty() { RECRUIT_CITIZEN_COOLDOWN_SECONDS_DEFAULT }
+        else { self.recruit_citizen_cooldown_seconds().get() }
+    }
+
+    /// Get the Character NFT name, with the custom character name if one is set
+    fn get_character_nft_name(&self, character_nft_nonce: u64, character: &Character) -> ManagedBuffer {
+        if self.character_name(character_nft_nonce).is_empty() { self.get_nft_name(character, character_nft_nonce) }
+        else { self.get_named_nft_name(character, &self.character_name(character_nft_nonce).get()) }
+    }
+
+    /// Get the base attack and defence of a character, without the stats of its equipped tools
+    fn get_base_stats(&self, character_nft_nonce: u64, character: &Character) -> (u8, u8) {
+        let mut base_attack = character.attack;
+        let mut base_defence = character.defence;
+
+        // The equipped tools are held by the SC
+        let sc_address = self.blockchain().get_sc_address();
+        for (_, tool_nft_nonce) in self.equipped_tools(character_nft_nonce).iter() {
+            let tool = self.get_tool(&sc_address, &self.tools_nft_collection().get(), tool_nft_nonce);
+            base_attack = base_attack.saturating_sub(tool.attack);
+            base_defence = base_defence.saturating_sub(tool.defence);
+        }
+
+        (base_attack, base_defence)
+    }
+
+    /// Check if the character stats may include a tool of the same type as the given tool that is not recorded in its slot
+    /// e.g., soldiers upgraded before the equipment slots existed
+    /// Soldiers start without stats and each level raises one base stat by 1, any other base stat comes from an unrecorded tool
+    fn has_unrecorded_tool(&self, character_nft_nonce: u64, character: &Character, tool: &Tool) -> bool {
+        let (base_attack, base_defence) = self.get_base_stats(character_nft_nonce, character);
+        let unrecorded_stats = (base_attack as u64 + base_defence as u64).saturating_sub(character.level as u64);
+
+        unrecorded_stats > 0 && ((tool.attack > 0 && base_attack > 0) || (tool.defence > 0 && base_defence > 0))
+    }
+
+    /// Check the character name and get its lowercase key used for the uniqueness index
+    /// Names have letters and digits separated by single spaces
+    fn get_character_name_key(&self, name: &ManagedBuffer) -> ManagedBuffer {
+        let name_length = name.len();
+        require!((CHARACTER_NAME_MIN_LENGTH..=CHARACTER_NAME_MAX_LENGTH).contains(&name_length),
+            "Name must have between {} and {} characters.", CHARACTER_NAME_MIN_LENGTH, CHARACTER_NAME_MAX_LENGTH);
+
+        let mut name_buffer = [0u8; CHARACTER_NAME_MAX_LENGTH];
+        name.load_to_byte_array(&mut name_buffer);
+        let name_bytes = &mut name_buffer[..name_length];
+        require!(name_bytes[0] != b' ' && name_bytes[name_length - 1] != b' ', "Name cannot start or end with a space.");
+
+        let mut previous_byte = 0u8;
+        for byte in name_bytes.iter_mut() {
+            require!(byte.is_ascii_alphanumeric() || *byte == b' ', "Name can only have letters, digits and spaces.");
+            require!(!(*byte == b' ' && previous_byte == b' '), "Name cannot have consecutive spaces.");
+            previous_byte = *byte;
+            byte.make_ascii_lowercase();
+        }
+
+        ManagedBuffer::new_from_bytes(name_bytes)
+    }
+
+    /// Get the seconds left before a pending citizen mint can be claimed
+    fn get_citizen_mint_remaining_seconds(&self, pending_mint: &PendingMint<Self::Api>) -> u64 {
+        let mint_citizen_seconds = if self.mint_citizen_seconds().is_empty() { MINT_CITIZEN_SECONDS_DEFAULT }
+            else { self.mint_citizen_seconds().get() };
+
+        (pending_mint.start_timestamp + mint_citizen_seconds).saturating_sub(self.blockchain().get_block_timestamp())
+    }
+
     /// Require that the character collection is issued
     fn require_character_collection(&self) {
         require!(!self.characters_nft_collection().is_empty(), "Character collection not issued");
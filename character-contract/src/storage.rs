@@ -1,5 +1,7 @@
 use multiversx_sc::imports::*;
 
+use game_common_module::data::PendingMint;
+
 
 #[multiversx_sc::module]
 pub trait StorageModule {
@@ -9,16 +11,70 @@ pub trait StorageModule {
     #[storage_mapper("nftTokenId")]
     fn characters_nft_collection(&self) -> NonFungibleTokenMapper;
 
-    /// Citizens to mint for each user
+    /// Citizens to mint for each user, with the wood and food held in escrow until claim
     #[view(getCitizensToMint)]
+    #[storage_mapper("pendingCitizenMints")]
+    fn citizens_to_mint(&self, user: &ManagedAddress) -> VecMapper<PendingMint<Self::Api>>;
+
+    /// Citizen mint start timestamps queued before the escrow, moved to the citizens to mint on the next user action
+    #[view(getLegacyCitizensToMint)]
     #[storage_mapper("citizensToMint")]
-    fn citizens_to_mint(&self, user: &ManagedAddress) -> VecMapper<u64>;
+    fn legacy_citizens_to_mint(&self, user: &ManagedAddress) -> VecMapper<u64>;
 
     /// Mint citizen seconds
     #[view(getMintCitizenSeconds)]
     #[storage_mapper("mintCitizenSeconds")]
     fn mint_citizen_seconds(&self) -> SingleValueMapper<u64>;
 
+    /// Fee token and amount paid to rename a character
+    #[view(getRenameFee)]
+    #[storage_mapper("renameFee")]
+    fn rename_fee(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    /// Treasury address receiving the rename fees
+    #[view(getRenameFeeTreasury)]
+    #[storage_mapper("renameFeeTreasury")]
+    fn rename_fee_treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Custom name of each character
+    #[view(getCharacterName)]
+    #[storage_mapper("characterName")]
+    fn character_name(&self, character_nft_nonce: u64) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Character NFT nonce for each lowercase character name, to keep the names unique
+    #[storage_mapper("characterNameIndex")]
+    fn character_name_index(&self, name_key: &ManagedBuffer) -> SingleValueMapper<u64>;
+
+    /// Share of the resources spent on a character returned when retiring it, in basis points
+    #[view(getRetireShare)]
+    #[storage_mapper("retireShare")]
+    fn retire_share(&self) -> SingleValueMapper<u64>;
+
+    /// WOOD, FOOD, GOLD and ORE reserve used to pay the retired characters
+    #[view(getRetireReserve)]
+    #[storage_mapper("retireReserve")]
+    fn retire_reserve(&self) -> MapMapper<TokenIdentifier, BigUint>;
+
+    /// Cooldown in seconds between two recruitments of a citizen
+    #[view(getRecruitCitizenCooldownSeconds)]
+    #[storage_mapper("recruitCitizenCooldownSeconds")]
+    fn recruit_citizen_cooldown_seconds(&self) -> SingleValueMapper<u64>;
+
+    /// Number of recruitments allowed for each citizen
+    #[view(getMaxCitizenRecruitments)]
+    #[storage_mapper("maxCitizenRecruitments")]
+    fn max_citizen_recruitments(&self) -> SingleValueMapper<u64>;
+
+    /// Number of recruitments done by each citizen
+    #[view(getCitizenRecruitments)]
+    #[storage_mapper("citizenRecruitments")]
+    fn citizen_recruitments(&self, citizen_nft_nonce: u64) -> SingleValueMapper<u64>;
+
+    /// Timestamp of the last recruitment done by each citizen
+    #[view(getCitizenLastRecruitment)]
+    #[storage_mapper("citizenLastRecruitment")]
+    fn citizen_last_recruitment(&self, citizen_nft_nonce: u64) -> SingleValueMapper<u64>;
+
     /// Last minted NFT nonce
     #[view(getLastMintedNftNonce)]
     #[storage_mapper("lastMintedNftNonce")]
@@ -28,4 +84,14 @@ pub trait StorageModule {
     #[view(getToolsNftCollection)]  
     #[storage_mapper("toolsCollectionId")]
     fn tools_nft_collection(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Game arena contract address allowed to add experience to characters
+    #[view(getArenaContractAddress)]
+    #[storage_mapper("arenaContractAddress")]
+    fn arena_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Tool NFT nonces equipped on each character, by tool type (slot)
+    #[view(getEquippedTools)]
+    #[storage_mapper("equippedTools")]
+    fn equipped_tools(&self, character_nft_nonce: u64) -> MapMapper<u8, u64>;
 }
@@ -8,3 +8,53 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/character_contract.scen.json");
 }
+
+#[test]
+fn promote_base_stats_go() {
+    world().run("scenarios/promote_base_stats.scen.json");
+}
+
+#[test]
+fn equipment_slots_go() {
+    world().run("scenarios/equipment_slots.scen.json");
+}
+
+#[test]
+fn stat_limits_go() {
+    world().run("scenarios/stat_limits.scen.json");
+}
+
+#[test]
+fn soldier_classes_go() {
+    world().run("scenarios/soldier_classes.scen.json");
+}
+
+#[test]
+fn citizen_mint_queue_go() {
+    world().run("scenarios/citizen_mint_queue.scen.json");
+}
+
+#[test]
+fn legacy_citizen_mints_go() {
+    world().run("scenarios/legacy_citizen_mints.scen.json");
+}
+
+#[test]
+fn pending_mint_escrow_go() {
+    world().run("scenarios/pending_mint_escrow.scen.json");
+}
+
+#[test]
+fn character_names_go() {
+    world().run("scenarios/character_names.scen.json");
+}
+
+#[test]
+fn retire_character_go() {
+    world().run("scenarios/retire_character.scen.json");
+}
+
+#[test]
+fn recruit_retire_go() {
+    world().run("scenarios/recruit_retire.scen.json");
+}
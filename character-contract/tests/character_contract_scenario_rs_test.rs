@@ -12,3 +12,53 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/character_contract.scen.json");
 }
+
+#[test]
+fn promote_base_stats_rs() {
+    world().run("scenarios/promote_base_stats.scen.json");
+}
+
+#[test]
+fn equipment_slots_rs() {
+    world().run("scenarios/equipment_slots.scen.json");
+}
+
+#[test]
+fn stat_limits_rs() {
+    world().run("scenarios/stat_limits.scen.json");
+}
+
+#[test]
+fn soldier_classes_rs() {
+    world().run("scenarios/soldier_classes.scen.json");
+}
+
+#[test]
+fn citizen_mint_queue_rs() {
+    world().run("scenarios/citizen_mint_queue.scen.json");
+}
+
+#[test]
+fn legacy_citizen_mints_rs() {
+    world().run("scenarios/legacy_citizen_mints.scen.json");
+}
+
+#[test]
+fn pending_mint_escrow_rs() {
+    world().run("scenarios/pending_mint_escrow.scen.json");
+}
+
+#[test]
+fn character_names_rs() {
+    world().run("scenarios/character_names.scen.json");
+}
+
+#[test]
+fn retire_character_rs() {
+    world().run("scenarios/retire_character.scen.json");
+}
+
+#[test]
+fn recruit_retire_rs() {
+    world().run("scenarios/recruit_retire.scen.json");
+}
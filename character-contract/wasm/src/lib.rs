@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           12
+// Endpoints:                           47
 // Async Callback:                       1
-// Total number of exported functions:  15
+// Total number of exported functions:  50
 
 #![no_std]
 
@@ -22,16 +22,51 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         mintCitizen => mint_citizen
         claimCitizen => claim_citizen
+        cancelPendingMint => cancel_pending_mint
+        speedUpPendingMint => speed_up_pending_mint
+        getSpeedUpCost => get_speed_up_cost
+        recruitCitizen => recruit_citizen
         upgradeCitizenToSoldier => upgrade_citizen_to_soldier
         upgradeSoldier => upgrade_soldier
+        unequipTool => unequip_tool
+        addExperience => add_experience
+        levelUp => level_up
+        promote => promote
+        renameCharacter => rename_character
+        getCharacterByName => get_character_by_name
+        retireCharacter => retire_character
         registerCharactersCollection => register_characters_collection
         setMintCitizenSeconds => set_mint_citizen_seconds
+        setRecruitCitizenCooldownSeconds => set_recruit_citizen_cooldown_seconds
+        setMaxCitizenRecruitments => set_max_citizen_recruitments
+        setRenameFee => set_rename_fee
+        setRetireShare => set_retire_share
+        fundRetireReserve => fund_retire_reserve
+        setArenaContractAddress => set_arena_contract_address
         setToolsCollectionId => set_tools_collection_id
         getCharactersNftCollection => characters_nft_collection
         getCitizensToMint => citizens_to_mint
+        getLegacyCitizensToMint => legacy_citizens_to_mint
         getMintCitizenSeconds => mint_citizen_seconds
+        getRenameFee => rename_fee
+        getRenameFeeTreasury => rename_fee_treasury
+        getCharacterName => character_name
+        getRetireShare => retire_share
+        getRetireReserve => retire_reserve
+        getRecruitCitizenCooldownSeconds => recruit_citizen_cooldown_seconds
+        getMaxCitizenRecruitments => max_citizen_recruitments
+        getCitizenRecruitments => citizen_recruitments
+        getCitizenLastRecruitment => citizen_last_recruitment
         getLastMintedNftNonce => last_minted_nft_nonce
         getToolsNftCollection => tools_nft_collection
+        getArenaContractAddress => arena_contract_address
+        getEquippedTools => equipped_tools
+        setCancelMintFee => set_cancel_mint_fee
+        setSpeedUpPrice => set_speed_up_price
+        setSpeedUpTreasury => set_speed_up_treasury
+        getCancelMintFee => cancel_mint_fee
+        getSpeedUpPrice => speed_up_price
+        getSpeedUpTreasury => speed_up_treasury
     )
 }
 
@@ -7,6 +7,7 @@ use multiversx_sc::derive::*;
 use multiversx_sc::proxy_imports::*;
 
 use game_common_module::data::Character;
+use game_common_module::constants::*;
 
 pub const ERR_CHARACTER_COLLECTION_NOT_SET: &str = "Character NFT collection is not set.";
 
@@ -55,6 +56,20 @@ pub trait GameArenaContract:
         self.characters_nft_collection().set_if_empty(collection_id);
     }
 
+    /// Set the character contract address used to award experience points after each battle
+    #[only_owner]
+    #[endpoint(setCharacterContractAddress)]
+    fn set_character_contract_address(&self, character_contract_address: ManagedAddress) {
+        self.character_contract_address().set(character_contract_address);
+    }
+
+    /// Set the game interface contract address allowed to create and accept games on behalf of its users
+    #[only_owner]
+    #[endpoint(setGameInterfaceAddress)]
+    fn set_game_interface_address(&self, game_interface_address: ManagedAddress) {
+        self.game_interface_address().set(game_interface_address);
+    }
+
     /// Creates a new game
     #[payable]
     #[endpoint(createGame)]
@@ -67,10 +82,7 @@ pub trait GameArenaContract:
         require!(transfers.len() == 2, "Game requires 2 transfers, a Soldier NFT and the fee token amount.");
 
         // Get the initiator user address
-        let initiator = match initiator_address {
-            OptionalValue::Some(address) => address,
-            OptionalValue::None => self.blockchain().get_caller(),
-        };
+        let (initiator, initiator_verified) = self.get_player(initiator_address);
         
         // Initialize variables
         let mut character_nonce = 0u64;
@@ -90,7 +102,7 @@ pub trait GameArenaContract:
                 character_nonce = transfer.token_nonce;
                 // Get the character object
                 let character = self.get_character(&self.blockchain().get_sc_address(), &self.characters_nft_collection().get_token_id(), character_nonce);
-                require!(character.is_soldier(), "Character NFT is not a soldier.");
+                require!(character.is_military(), "Character NFT is not a soldier.");
                 require!(character.attack > 0 || character.defence > 0, "Soldier NFT is not an upgraded soldier.");
                 soldier = Some(character);
                 continue;
@@ -116,6 +128,9 @@ pub trait GameArenaContract:
         // Store the game
         self.open_games().insert(game_id, new_game);
         self.last_game_id().set(game_id);
+        if initiator_verified {
+            self.verified_games().insert(game_id);
+        }
 
     }
 
@@ -137,10 +152,8 @@ pub trait GameArenaContract:
         };
 
         // Get the competitor
-        let competitor = match competitor_address {
-            OptionalValue::Some(address) => address,
-            OptionalValue::None => self.blockchain().get_caller(),
-        };
+        let (competitor, competitor_verified) = self.get_player(competitor_address);
+        let players_verified = self.verified_games().swap_remove(&game_id) && competitor_verified;
 
         // Check if the competitor is the initiator
         // require!(game.initiator != competitor, "Initiator cannot join their own game.");
@@ -166,7 +179,7 @@ pub trait GameArenaContract:
                 character_nonce = transfer.token_nonce;
                 // Get the character object
                 let character = self.get_character(&self.blockchain().get_sc_address(), &character_collection_id, character_nonce);
-                require!(character.is_soldier(), "Character NFT is not a soldier.");
+                require!(character.is_military(), "Character NFT is not a soldier.");
                 require!(character.attack > 0 || character.defence > 0, "Soldier NFT is not an upgraded soldier.");
                 competitor_soldier = Some(character);
                 continue;
@@ -210,6 +223,15 @@ pub trait GameArenaContract:
         // Set the winner
         game.winner_soldier_nonce = winner_soldier;
 
+        // Award experience points to both soldiers, more for the winner
+        // No experience points for a game against the same address, so soldiers cannot be trained by self-play
+        // nor if any player address cannot be verified, as it could be a different address given by the same caller
+        if players_verified && game.competitor.as_ref() != Some(&game.initiator) {
+            let loser_soldier = if winner_soldier == game.initiator_soldier_nonce { game.competitor_soldier_nonce }
+                else { game.initiator_soldier_nonce };
+            self.award_experience(winner_soldier, loser_soldier);
+        }
+
         // Remove the game from the open games
         self.open_games().remove(&game_id);
         // Add the game to the completed games
@@ -270,6 +292,15 @@ pub trait GameArenaContract:
             base_chance - (competency_difference as u64 / 2)
         };
 
+        // Adjust by the class advantage (Infantry > Cavalry > Archer > Infantry)
+        let competitor_win_chance = if competitor_soldier.counters(&initiator_soldier) {
+            competitor_win_chance + CLASS_ADVANTAGE_WIN_CHANCE
+        } else if initiator_soldier.counters(&competitor_soldier) {
+            competitor_win_chance.saturating_sub(CLASS_ADVANTAGE_WIN_CHANCE)
+        } else {
+            competitor_win_chance
+        };
+
         // Limit min and max to 1-99% chance for competency difference close to 100
         let competitor_win_chance = competitor_win_chance.clamp(1, 99);
 
@@ -279,6 +310,38 @@ pub trait GameArenaContract:
     }
 
 
+    /// Calls the character contract to add experience points to the winner and loser soldiers
+    /// The soldiers are still held by the SC at this point
+    fn award_experience(&self, winner_soldier_nonce: u64, loser_soldier_nonce: u64) {
+        if self.character_contract_address().is_empty() {
+            return;
+        }
+
+        let mut characters_xp = MultiValueEncoded::<Self::Api, MultiValue2<u64, u64>>::new();
+        characters_xp.push(MultiValue2::from((winner_soldier_nonce, ARENA_WINNER_XP)));
+        characters_xp.push(MultiValue2::from((loser_soldier_nonce, ARENA_LOSER_XP)));
+
+        self.tx()
+            .to(self.character_contract_address().get())
+            .raw_call(CHARACTER_CONTRACT_ADD_EXPERIENCE_ENDPOINT_NAME)
+            .argument(&self.blockchain().get_sc_address())
+            .argument(&characters_xp)
+            .sync_call();
+    }
+
+    /// Get the player address and whether it is verified
+    /// The player address is verified if it's the caller, or if it's given by the game interface contract on behalf of its user
+    fn get_player(&self, player_address: OptionalValue<ManagedAddress>) -> (ManagedAddress, bool) {
+        let caller = self.blockchain().get_caller();
+        match player_address {
+            OptionalValue::Some(address) => {
+                let verified = !self.game_interface_address().is_empty() && caller == self.game_interface_address().get();
+                (address, verified)
+            },
+            OptionalValue::None => (caller, true),
+        }
+    }
+
     /// Require the characters NFT collection is set
     fn require_characters_nft_collection(&self) {
         require!(!self.characters_nft_collection().is_empty(), ERR_CHARACTER_COLLECTION_NOT_SET);
@@ -299,6 +362,20 @@ pub trait GameArenaContract:
     #[storage_mapper("characters_nft_collection")]
     fn characters_nft_collection(&self) -> NonFungibleTokenMapper;
 
+    /// Character contract address
+    #[view(getCharacterContractAddress)]
+    #[storage_mapper("character_contract_address")]
+    fn character_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Game interface contract address, the player addresses it gives are verified
+    #[view(getGameInterfaceAddress)]
+    #[storage_mapper("game_interface_address")]
+    fn game_interface_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Open games whose initiator address is verified
+    #[storage_mapper("verified_games")]
+    fn verified_games(&self) -> UnorderedSetMapper<u64>;
+
     /// Last game ID
     #[view(getLastGameId)]
     #[storage_mapper("last_game_id")]
@@ -8,3 +8,18 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/game_arena_contract.scen.json");
 }
+
+#[test]
+fn self_play_go() {
+    world().run("scenarios/self_play.scen.json");
+}
+
+#[test]
+fn player_verification_go() {
+    world().run("scenarios/player_verification.scen.json");
+}
+
+#[test]
+fn class_advantage_go() {
+    world().run("scenarios/class_advantage.scen.json");
+}
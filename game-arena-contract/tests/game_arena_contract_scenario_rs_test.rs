@@ -12,3 +12,18 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/game_arena_contract.scen.json");
 }
+
+#[test]
+fn self_play_rs() {
+    world().run("scenarios/self_play.scen.json");
+}
+
+#[test]
+fn player_verification_rs() {
+    world().run("scenarios/player_verification.scen.json");
+}
+
+#[test]
+fn class_advantage_rs() {
+    world().run("scenarios/class_advantage.scen.json");
+}
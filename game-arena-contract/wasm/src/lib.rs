@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            7
+// Endpoints:                           11
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions:  14
 
 #![no_std]
 
@@ -21,11 +21,15 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         setCharactersNftCollection => set_characters_nft_collection
+        setCharacterContractAddress => set_character_contract_address
+        setGameInterfaceAddress => set_game_interface_address
         createGame => create_game
         acceptGame => accept_game
         getOpenGames => open_games
         getCompletedGames => completed_games
         getCharactersNftCollection => characters_nft_collection
+        getCharacterContractAddress => character_contract_address
+        getGameInterfaceAddress => game_interface_address
         getLastGameId => last_game_id
     )
 }
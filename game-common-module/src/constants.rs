@@ -12,6 +12,7 @@ pub const ORE_TICKER: &str = "ORE-";
 /// Game settings
 pub const MINT_CITIZEN_WOOD_QUANTITY: u64 = 10;
 pub const MINT_CITIZEN_FOOD_QUANTITY: u64 = 15;
+pub const MAX_MINT_CITIZEN_COUNT: u64 = 100; // Citizens minted in one transaction
 pub const STONE_AMMOUNT_FOR_ORE: u64 = 20;
 pub const MINT_SHIELD_ORE_QUANTITY: u64 = 2;
 pub const MINT_SWORD_GOLD_QUANTITY: u64 = 1;
@@ -19,33 +20,103 @@ pub const MINT_SWORD_ORE_QUANTITY: u64 = 3;
 pub const CITIZEN_TO_SOLDIER_GOLD_QUANTITY: u64 = 5;
 pub const CITIZEN_TO_SOLDIER_ORE_QUANTITY: u64 = 5;
 pub const MINT_CITIZEN_SECONDS_DEFAULT: u64 = 3600;
+pub const RECRUIT_CITIZEN_WOOD_QUANTITY: u64 = 5;
+pub const RECRUIT_CITIZEN_FOOD_QUANTITY: u64 = 8;
+pub const RECRUIT_CITIZEN_COOLDOWN_SECONDS_DEFAULT: u64 = 86400; // 1 day between recruitments for each citizen
+pub const MAX_CITIZEN_RECRUITMENTS_DEFAULT: u64 = 3; // Recruitments allowed for each citizen
 pub const MINT_SHIELD_SECONDS_DEFAULT: u64 = 3600;
 pub const MINT_SWORD_SECONDS_DEFAULT: u64 = 3600;
+pub const PROMOTE_TO_KNIGHT_MIN_ATTACK: u8 = 2;
+pub const PROMOTE_TO_KNIGHT_MIN_DEFENCE: u8 = 2;
+pub const PROMOTE_TO_KNIGHT_GOLD_QUANTITY: u64 = 10;
+pub const PROMOTE_TO_KNIGHT_ORE_QUANTITY: u64 = 10;
+pub const PROMOTE_TO_CAPTAIN_MIN_ATTACK: u8 = 4;
+pub const PROMOTE_TO_CAPTAIN_MIN_DEFENCE: u8 = 4;
+pub const PROMOTE_TO_CAPTAIN_GOLD_QUANTITY: u64 = 20;
+pub const PROMOTE_TO_CAPTAIN_ORE_QUANTITY: u64 = 20;
+pub const PROMOTE_TO_GENERAL_MIN_ATTACK: u8 = 6;
+pub const PROMOTE_TO_GENERAL_MIN_DEFENCE: u8 = 6;
+pub const PROMOTE_TO_GENERAL_GOLD_QUANTITY: u64 = 40;
+pub const PROMOTE_TO_GENERAL_ORE_QUANTITY: u64 = 40;
+pub const ARENA_WINNER_XP: u64 = 30;
+pub const ARENA_LOSER_XP: u64 = 10;
+pub const LEVEL_UP_XP: u64 = 100; // XP needed for each level
+pub const LEVEL_UP_STAT_ATTACK: u8 = 1;
+pub const LEVEL_UP_STAT_DEFENCE: u8 = 2;
+pub const MAX_CHARACTER_STAT: u8 = 100; // Maximum attack or defence, equipped tools included
+pub const CLASS_ADVANTAGE_WIN_CHANCE: u64 = 10; // % added to the win chance against the countered class
+pub const SALVAGE_SHARE_DEFAULT: u64 = 5_000; // 50% in basis points
+pub const MAX_SALVAGE_SHARE: u64 = 10_000; // 100% in basis points
+pub const RETIRE_SHARE_DEFAULT: u64 = 5_000; // 50% in basis points
+pub const MAX_RETIRE_SHARE: u64 = 10_000; // 100% in basis points
+pub const CANCEL_MINT_FEE_DEFAULT: u64 = 1_000; // 10% in basis points
+pub const MAX_CANCEL_MINT_FEE: u64 = 10_000; // 100% in basis points
+pub const CHARACTER_NAME_MIN_LENGTH: usize = 3;
+pub const CHARACTER_NAME_MAX_LENGTH: usize = 20;
+/// Character ranks
+pub const CHARACTER_RANK_CITIZEN: u8 = 0;
+pub const CHARACTER_RANK_SOLDIER: u8 = 1;
+pub const CHARACTER_RANK_KNIGHT: u8 = 2;
+pub const CHARACTER_RANK_CAPTAIN: u8 = 3;
+pub const CHARACTER_RANK_GENERAL: u8 = 4;
+/// Character classes
+pub const CHARACTER_CLASS_NONE: u8 = 0;
+pub const CHARACTER_CLASS_INFANTRY: u8 = 1;
+pub const CHARACTER_CLASS_ARCHER: u8 = 2;
+pub const CHARACTER_CLASS_CAVALRY: u8 = 3;
+/// Character origins, how the citizen was obtained
+pub const CHARACTER_ORIGIN_MINTED: u8 = 0;
+pub const CHARACTER_ORIGIN_RECRUITED: u8 = 1;
+/// Land types
+pub const LAND_TYPE_FOREST: u8 = 1; // WOOD
+pub const LAND_TYPE_FARM: u8 = 2; // FOOD
+pub const LAND_TYPE_QUARRY: u8 = 3; // STONE
+pub const LAND_TYPE_MINE: u8 = 4; // GOLD
 // NFT Collections settings
 pub const CHARACTER_COLLECTION_NAME: &str = "Characters";
 pub const CHARACTER_COLLECTION_TICKER: &str = "CHARACTER";
 pub const TOOLS_COLLECTION_NAME: &str = "Tools";
 pub const TOOLS_COLLECTION_TICKER: &str = "TOOLS";
+pub const LANDS_COLLECTION_NAME: &str = "Lands";
+pub const LANDS_COLLECTION_TICKER: &str = "LANDS";
 /// NFT names
 pub const CITIZEN_NFT_NAME: &str = "Citizen";
 pub const SOLDIER_NFT_NAME: &str = "Soldier";
+pub const KNIGHT_NFT_NAME: &str = "Knight";
+pub const CAPTAIN_NFT_NAME: &str = "Captain";
+pub const GENERAL_NFT_NAME: &str = "General";
 pub const SHIELD_NFT_NAME: &str = "Shield";
 pub const SWORD_NFT_NAME: &str = "Sword";
+pub const FOREST_NFT_NAME: &str = "Forest";
+pub const FARM_NFT_NAME: &str = "Farm";
+pub const QUARRY_NFT_NAME: &str = "Quarry";
+pub const MINE_NFT_NAME: &str = "Mine";
 /// NFT royalties
 pub const CHARACTER_NFT_ROYALTIES: u64 = 500; // 5%
 pub const SHIELD_NFT_ROYALTIES: u64 = 500; // 5%
 pub const SWORD_NFT_ROYALTIES: u64 = 500; // 5%
+pub const LAND_NFT_ROYALTIES: u64 = 500; // 5%
 /// NFT IPFS CIDs
 pub const IPFS_CHARACTERS_CID: &str = "bafybeih3vwnfq7qyvyb5s2ojjk4cs6gcwxzpatujtahpeiap5xu5k4r3pm";
 pub const IPFS_TOOLS_CID: &str = "bafybeieysc7cv3cgwfdjdhujmmvscca4h67mbidbnbfzchyad4lib2ocpu";
 /// NFT tags
 pub const CITIZEN_NFT_TAGS : &str = "character,citizen";
 pub const SOLDIER_NFT_TAGS : &str = "character,soldier";
+pub const KNIGHT_NFT_TAGS : &str = "character,knight";
+pub const CAPTAIN_NFT_TAGS : &str = "character,captain";
+pub const GENERAL_NFT_TAGS : &str = "character,general";
 pub const SHIELD_NFT_TAGS : &str = "tool,shield";
 pub const SWORD_NFT_TAGS : &str = "tool,sword";
+pub const FOREST_NFT_TAGS : &str = "land,forest";
+pub const FARM_NFT_TAGS : &str = "land,farm";
+pub const QUARRY_NFT_TAGS : &str = "land,quarry";
+pub const MINE_NFT_TAGS : &str = "land,mine";
 /// NFT Assets files
 pub const CITIZEN_FILE_NAME : &str = "citizen";
 pub const SOLDIER_FILE_NAME : &str = "soldier";
+pub const KNIGHT_FILE_NAME : &str = "knight";
+pub const CAPTAIN_FILE_NAME : &str = "captain";
+pub const GENERAL_FILE_NAME : &str = "general";
 pub const SHIELD_FILE_NAME : &str = "shield";
 pub const SWORD_FILE_NAME : &str = "sword";
 // NFT Assets files extensions
@@ -54,6 +125,7 @@ pub const NFT_METADATA_FILE_EXTENSION: &str = "json";
 /// NFT attributes prefixes
 pub const NFT_CHARACTER_ATTRIBUTES_PREFIX: &str = ";c:";
 pub const NFT_TOOL_ATTRIBUTES_PREFIX: &str = ";t:";
+pub const NFT_LAND_ATTRIBUTES_PREFIX: &str = ";l:";
 /// Game contracts endpoints
 pub const RESOURCE_CONTRACT_MINT_RESOURCES_ENDPOINT_NAME: &str = "mintResources";
 pub const RESOURCE_CONTRACT_CLAIM_RESOURCES_ENDPOINT_NAME: &str = "claimResources";
@@ -62,6 +134,7 @@ pub const CHARACTER_CONTRACT_MINT_CITIZEN_ENDPOINT_NAME: &str = "mintCitizen";
 pub const CHARACTER_CONTRACT_CLAIM_CITIZEN_ENDPOINT_NAME: &str = "claimCitizen";
 pub const CHARACTER_CONTRACT_UPGRADE_CITIZEN_TO_SOLDIER_ENDPOINT_NAME: &str = "upgradeCitizenToSoldier";
 pub const CHARACTER_CONTRACT_UPGRADE_SOLDIER_ENDPOINT_NAME: &str = "upgradeSoldier";
+pub const CHARACTER_CONTRACT_ADD_EXPERIENCE_ENDPOINT_NAME: &str = "addExperience";
 pub const TOOLS_CONTRACT_MINT_SHIELD_ENDPOINT_NAME: &str = "mintShield";
 pub const TOOLS_CONTRACT_MINT_SWORD_ENDPOINT_NAME: &str = "mintSword";
 pub const TOOLS_CONTRACT_CLAIM_SHIELD_ENDPOINT_NAME: &str = "claimShield";
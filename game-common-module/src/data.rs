@@ -1,5 +1,9 @@
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
+use multiversx_sc::derive::*;
+use multiversx_sc::proxy_imports::*;
+
+use crate::constants::*;
 
 
 /// Character object to hold character attributes
@@ -7,39 +11,209 @@ pub struct Character {
     pub rank: u8,
     pub attack: u8,
     pub defence: u8,
+    pub xp: u64,
+    pub level: u8,
+    pub class: u8,
+    pub origin: u8,
 }
 
 impl Character {
-    /// Create a new citizen character
-    pub fn new_citizen() -> Self {
+    /// Create a new citizen character obtained with the given origin (minted or recruited)
+    pub fn new_citizen(origin: u8) -> Self {
         Self {
-            rank: 0,
+            rank: CHARACTER_RANK_CITIZEN,
             attack: 0,
             defence: 0,
+            xp: 0,
+            level: 0,
+            class: CHARACTER_CLASS_NONE,
+            origin,
         }
     }
-    /// Create a new soldier character
-    pub fn new_soldier() -> Self {
+    /// Create a new soldier character of the given class, keeping the origin of the citizen
+    pub fn new_soldier(class: u8, origin: u8) -> Self {
         Self {
-            rank: 1,
+            rank: CHARACTER_RANK_SOLDIER,
             attack: 0,
             defence: 0,
+            xp: 0,
+            level: 0,
+            class,
+            origin,
         }
     }
+
+    /// Check if the class is a valid soldier class (Infantry, Archer or Cavalry)
+    pub fn is_valid_class(class: u8) -> bool {
+        (CHARACTER_CLASS_INFANTRY..=CHARACTER_CLASS_CAVALRY).contains(&class)
+    }
+
+    /// Check if the character class counters the other character class
+    /// Infantry counters Cavalry, Cavalry counters Archer, Archer counters Infantry
+    pub fn counters(&self, other: &Character) -> bool {
+        matches!((self.class, other.class),
+            (CHARACTER_CLASS_INFANTRY, CHARACTER_CLASS_CAVALRY) |
+            (CHARACTER_CLASS_CAVALRY, CHARACTER_CLASS_ARCHER) |
+            (CHARACTER_CLASS_ARCHER, CHARACTER_CLASS_INFANTRY))
+    }
     /// Check if the character is a citizen
     pub fn is_citizen(&self) -> bool {
-        self.rank == 0
+        self.rank == CHARACTER_RANK_CITIZEN
     }
 
     /// Check if the character is a soldier
     pub fn is_soldier(&self) -> bool {
-        self.rank == 1
+        self.rank == CHARACTER_RANK_SOLDIER
+    }
+
+    /// Check if the character is a knight
+    pub fn is_knight(&self) -> bool {
+        self.rank == CHARACTER_RANK_KNIGHT
+    }
+
+    /// Check if the character is a captain
+    pub fn is_captain(&self) -> bool {
+        self.rank == CHARACTER_RANK_CAPTAIN
+    }
+
+    /// Check if the character is a general
+    pub fn is_general(&self) -> bool {
+        self.rank == CHARACTER_RANK_GENERAL
+    }
+
+    /// Check if the character is a soldier or a higher rank
+    pub fn is_military(&self) -> bool {
+        self.rank >= CHARACTER_RANK_SOLDIER && self.rank <= CHARACTER_RANK_GENERAL
+    }
+
+    /// Add experience points to the character
+    pub fn add_xp(&mut self, xp: u64) {
+        self.xp = self.xp.saturating_add(xp);
+    }
+
+    /// Get the total experience points needed to reach the next level
+    pub fn next_level_xp(&self) -> u64 {
+        (self.level as u64 + 1) * LEVEL_UP_XP
+    }
+
+    /// Check if the character has enough experience points to level up
+    pub fn can_level_up(&self) -> bool {
+        self.level < u8::MAX && self.xp >= self.next_level_xp()
+    }
+
+    /// Level up the character and raise the given base stat (attack or defence)
+    /// Returns false and keeps the character unchanged if the stat would exceed the maximum stat
+    pub fn level_up(&mut self, stat: u8) -> bool {
+        let Some(level) = self.level.checked_add(1) else { return false };
+        let attack = if stat == LEVEL_UP_STAT_ATTACK { Self::raise_stat(self.attack, 1) } else { Some(self.attack) };
+        let defence = if stat == LEVEL_UP_STAT_DEFENCE { Self::raise_stat(self.defence, 1) } else { Some(self.defence) };
+        let (Some(attack), Some(defence)) = (attack, defence) else { return false };
+
+        self.level = level;
+        self.attack = attack;
+        self.defence = defence;
+        true
+    }
+
+    /// Promote the character to the next rank
+    pub fn promote(&mut self) {
+        self.rank += 1;
     }
 
     /// Upgrade the character with the given tool
-    pub fn upgrade(&mut self, tool: &Tool) {
-        self.attack += tool.attack;
-        self.defence += tool.defence;
+    /// Returns false and keeps the character unchanged if a stat would exceed the maximum stat
+    pub fn upgrade(&mut self, tool: &Tool) -> bool {
+        let (Some(attack), Some(defence)) = (Self::raise_stat(self.attack, tool.attack), Self::raise_stat(self.defence, tool.defence))
+            else { return false };
+
+        self.attack = attack;
+        self.defence = defence;
+        true
+    }
+
+    /// Raise a stat by the given increase, None if the stat would exceed the maximum stat
+    fn raise_stat(stat: u8, increase: u8) -> Option<u8> {
+        stat.checked_add(increase).filter(|raised_stat| *raised_stat <= MAX_CHARACTER_STAT)
+    }
+
+    /// Remove the given tool stats from the character
+    pub fn downgrade(&mut self, tool: &Tool) {
+        self.attack = self.attack.saturating_sub(tool.attack);
+        self.defence = self.defence.saturating_sub(tool.defence);
+    }
+
+    /// Check if the character was obtained by recruitment
+    pub fn is_recruited(&self) -> bool {
+        self.origin == CHARACTER_ORIGIN_RECRUITED
+    }
+
+    /// Get the resources spent on the character: the citizen mint or recruitment, the soldier upgrade and the promotions
+    pub fn resources_spent(&self) -> ResourceCost {
+        let (wood_quantity, food_quantity) = if self.is_recruited() { (RECRUIT_CITIZEN_WOOD_QUANTITY, RECRUIT_CITIZEN_FOOD_QUANTITY) }
+            else { (MINT_CITIZEN_WOOD_QUANTITY, MINT_CITIZEN_FOOD_QUANTITY) };
+        let mut cost = ResourceCost {
+            wood_quantity,
+            food_quantity,
+            gold_quantity: 0,
+            ore_quantity: 0,
+        };
+
+        if self.is_military() {
+            cost.gold_quantity += CITIZEN_TO_SOLDIER_GOLD_QUANTITY;
+            cost.ore_quantity += CITIZEN_TO_SOLDIER_ORE_QUANTITY;
+        }
+
+        for rank in CHARACTER_RANK_KNIGHT..=self.rank {
+            if let Some(requirements) = PromotionRequirements::for_rank(rank) {
+                cost.gold_quantity += requirements.gold_quantity;
+                cost.ore_quantity += requirements.ore_quantity;
+            }
+        }
+
+        cost
+    }
+}
+
+/// Resource quantities spent on a character
+pub struct ResourceCost {
+    pub wood_quantity: u64,
+    pub food_quantity: u64,
+    pub gold_quantity: u64,
+    pub ore_quantity: u64,
+}
+
+/// Promotion requirements to reach a rank
+pub struct PromotionRequirements {
+    pub min_attack: u8,
+    pub min_defence: u8,
+    pub gold_quantity: u64,
+    pub ore_quantity: u64,
+}
+
+impl PromotionRequirements {
+    /// Get the promotion requirements to reach the given rank, None if the rank cannot be reached by promotion
+    pub fn for_rank(rank: u8) -> Option<Self> {
+        match rank {
+            CHARACTER_RANK_KNIGHT => Some(Self {
+                min_attack: PROMOTE_TO_KNIGHT_MIN_ATTACK,
+                min_defence: PROMOTE_TO_KNIGHT_MIN_DEFENCE,
+                gold_quantity: PROMOTE_TO_KNIGHT_GOLD_QUANTITY,
+                ore_quantity: PROMOTE_TO_KNIGHT_ORE_QUANTITY,
+            }),
+            CHARACTER_RANK_CAPTAIN => Some(Self {
+                min_attack: PROMOTE_TO_CAPTAIN_MIN_ATTACK,
+                min_defence: PROMOTE_TO_CAPTAIN_MIN_DEFENCE,
+                gold_quantity: PROMOTE_TO_CAPTAIN_GOLD_QUANTITY,
+                ore_quantity: PROMOTE_TO_CAPTAIN_ORE_QUANTITY,
+            }),
+            CHARACTER_RANK_GENERAL => Some(Self {
+                min_attack: PROMOTE_TO_GENERAL_MIN_ATTACK,
+                min_defence: PROMOTE_TO_GENERAL_MIN_DEFENCE,
+                gold_quantity: PROMOTE_TO_GENERAL_GOLD_QUANTITY,
+                ore_quantity: PROMOTE_TO_GENERAL_ORE_QUANTITY,
+            }),
+            _ => None,
+        }
     }
 }
 
@@ -79,4 +253,58 @@ impl Tool {
         self.tool_type == 2
     }
 
-}
\ No newline at end of file
+}
+
+/// Land object to hold land attributes
+pub struct Land {
+    pub land_type: u8,
+    pub tier: u8,
+}
+
+impl Land {
+    /// Create a new tier 1 land of the given type
+    pub fn new_land(land_type: u8) -> Self {
+        Self {
+            land_type,
+            tier: 1,
+        }
+    }
+
+    /// Check if the land type is one of the known land types
+    pub fn is_valid_land_type(land_type: u8) -> bool {
+        (LAND_TYPE_FOREST..=LAND_TYPE_MINE).contains(&land_type)
+    }
+
+    /// Check if the land is a forest
+    pub fn is_forest(&self) -> bool {
+        self.land_type == LAND_TYPE_FOREST
+    }
+
+    /// Check if the land is a farm
+    pub fn is_farm(&self) -> bool {
+        self.land_type == LAND_TYPE_FARM
+    }
+
+    /// Check if the land is a quarry
+    pub fn is_quarry(&self) -> bool {
+        self.land_type == LAND_TYPE_QUARRY
+    }
+
+    /// Check if the land is a mine
+    pub fn is_mine(&self) -> bool {
+        self.land_type == LAND_TYPE_MINE
+    }
+
+    /// Upgrade the land to the next tier
+    pub fn upgrade(&mut self) {
+        self.tier += 1;
+    }
+}
+
+/// Pending NFT mint with its start timestamp and the payments held in escrow until claim
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct PendingMint<M: ManagedTypeApi> {
+    pub start_timestamp: u64,
+    pub payments: ManagedVec<M, EsdtTokenPayment<M>>,
+}
@@ -3,6 +3,12 @@
 pub mod constants;
 pub mod data;
 pub mod nft_attributes;
+pub mod pending_mint;
+
+use multiversx_sc::imports::*;
+
+use data::PendingMint;
+use constants::MAX_CANCEL_MINT_FEE;
 
 #[multiversx_sc::module]
 pub trait GameCommonModule {
@@ -13,4 +19,54 @@ pub trait GameCommonModule {
          == *required_token_ticker
     }
 
+    /// Burn the payments held in escrow for a claimed pending mint
+    fn burn_pending_mint_payments(&self, pending_mint: &PendingMint<Self::Api>) {
+        for payment in pending_mint.payments.iter() {
+            self.send().esdt_local_burn(&payment.token_identifier, payment.token_nonce, &payment.amount);
+        }
+    }
+
+    /// Refund the payments held in escrow for a cancelled pending mint
+    /// The fee share, in basis points, is burned and the rest is sent to the user
+    fn refund_pending_mint_payments(&self, user: &ManagedAddress, pending_mint: &PendingMint<Self::Api>, cancel_fee: u64) {
+        let mut refunds = ManagedVec::new();
+        for payment in pending_mint.payments.iter() {
+            let fee_amount = &payment.amount * cancel_fee / MAX_CANCEL_MINT_FEE;
+            if fee_amount > 0 {
+                self.send().esdt_local_burn(&payment.token_identifier, payment.token_nonce, &fee_amount);
+            }
+            let refund_amount = &payment.amount - &fee_amount;
+            if refund_amount > 0 {
+                refunds.push(EsdtTokenPayment::new(payment.token_identifier.clone(), payment.token_nonce, refund_amount));
+            }
+        }
+
+        if !refunds.is_empty() {
+            self.send().direct_multi(user, &refunds);
+        }
+    }
+
+    /// Add a payment to a token reserve
+    fn add_to_reserve(&self, mut reserve: MapMapper<TokenIdentifier, BigUint>, payment: &EsdtTokenPayment) {
+        let mut reserve_amount = reserve.get(&payment.token_identifier).unwrap_or_default();
+        reserve_amount += &payment.amount;
+        reserve.insert(payment.token_identifier.clone(), reserve_amount);
+    }
+
+    /// Take the amount of the token with the given ticker from a token reserve
+    fn take_from_reserve(&self, mut reserve: MapMapper<TokenIdentifier, BigUint>, reserve_name: &str, token_ticker: &str, amount: BigUint) -> EsdtTokenPayment {
+        let reserve_name = ManagedBuffer::from(reserve_name);
+        let required_ticker = ManagedBuffer::from(token_ticker);
+        let token_id = match reserve.keys().find(|token_id| self.is_required_token(token_id, &required_ticker)) {
+            Some(token_id) => token_id,
+            None => sc_panic!("The {} reserve has no {} tokens.", reserve_name, required_ticker),
+        };
+
+        let reserve_amount = reserve.get(&token_id).unwrap_or_default();
+        require!(reserve_amount >= amount, "Not enough {} in the {} reserve.", token_id, reserve_name);
+        reserve.insert(token_id.clone(), reserve_amount - &amount);
+
+        EsdtTokenPayment::new(token_id, 0, amount)
+    }
+
 }
\ No newline at end of file
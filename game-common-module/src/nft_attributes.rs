@@ -8,12 +8,12 @@ pub trait NftAttributesModule {
 
     // Encode NFT attributes
 
-    /// Encode nft attributes in the format: metadata:IPFS_CID/{filename}.json;tags:{tag(s)}{PREFIX}{rank}:{attack}:{defence}
-    /// Ex: metadata:IPFS_CID/citizen.json;tags:character,citizen;c:0:0:0
-    /// Ex: metadata:IPFS_CID/soldier21.json;tags:character,soldier;c:1:2:1
+    /// Encode nft attributes in the format: metadata:IPFS_CID/{filename}.json;tags:{tag(s)}{PREFIX}{rank}:{attack}:{defence}:{xp}:{level}:{class}:{origin}
+    /// Ex: metadata:IPFS_CID/citizen.json;tags:character,citizen;c:0:0:0:0:0:0:0
+    /// Ex: metadata:IPFS_CID/soldier21.json;tags:character,soldier;c:1:2:1:130:1:2:1
     fn get_nft_attributes(&self, character: &Character) -> ManagedBuffer {
         let nft_attributes = ManagedBuffer::from(
-            sc_format!("metadata:{}/{}.{};tags:{}{}{}:{}:{}",
+            sc_format!("metadata:{}/{}.{};tags:{}{}{}:{}:{}:{}:{}:{}:{}",
             ManagedBuffer::from(IPFS_CHARACTERS_CID),
             self.get_asset_filename(character),
             ManagedBuffer::from(NFT_METADATA_FILE_EXTENSION), 
@@ -21,7 +21,11 @@ pub trait NftAttributesModule {
             ManagedBuffer::from(NFT_CHARACTER_ATTRIBUTES_PREFIX), 
             character.rank, 
             character.attack, 
-            character.defence));
+            character.defence,
+            character.xp,
+            character.level,
+            character.class,
+            character.origin));
         nft_attributes
     }
 
@@ -50,9 +54,36 @@ pub trait NftAttributesModule {
     fn get_nft_tags(&self, character: &Character) -> ManagedBuffer {
         if character.is_citizen() { return ManagedBuffer::from(CITIZEN_NFT_TAGS) }
         if character.is_soldier() { return ManagedBuffer::from(SOLDIER_NFT_TAGS) }
+        if character.is_knight() { return ManagedBuffer::from(KNIGHT_NFT_TAGS) }
+        if character.is_captain() { return ManagedBuffer::from(CAPTAIN_NFT_TAGS) }
+        if character.is_general() { return ManagedBuffer::from(GENERAL_NFT_TAGS) }
         sc_panic!("Invalid character rank {}.", character.rank);
     }
 
+    /// Get the NFT name based on the character rank and the NFT nonce
+    /// Ex: Soldier 12, Knight 12
+    fn get_nft_name(&self, character: &Character, nft_nonce: u64) -> ManagedBuffer {
+        sc_format!("{} {}", self.get_rank_name(character), nft_nonce)
+    }
+
+    /// Get the NFT name based on the character rank and the custom character name
+    /// Ex: Soldier Arthur, Knight Arthur
+    fn get_named_nft_name(&self, character: &Character, character_name: &ManagedBuffer) -> ManagedBuffer {
+        sc_format!("{} {}", self.get_rank_name(character), character_name)
+    }
+
+    /// Get the rank name of the character
+    fn get_rank_name(&self, character: &Character) -> ManagedBuffer {
+        let rank_name = if character.is_citizen() { CITIZEN_NFT_NAME }
+            else if character.is_soldier() { SOLDIER_NFT_NAME }
+            else if character.is_knight() { KNIGHT_NFT_NAME }
+            else if character.is_captain() { CAPTAIN_NFT_NAME }
+            else if character.is_general() { GENERAL_NFT_NAME }
+            else { sc_panic!("Invalid character rank {}.", character.rank) };
+
+        ManagedBuffer::from(rank_name.as_bytes())
+    }
+
     /// Get the asset filename based on the character
     fn get_asset_filename(&self, character: &Character) -> ManagedBuffer {
         // One image and metadata for citizen
@@ -67,6 +98,10 @@ pub trait NftAttributesModule {
                 return sc_format!("{}XX", ManagedBuffer::from(SOLDIER_FILE_NAME))
             }
         }
+        // One image and metadata for each higher rank
+        if character.is_knight() { return ManagedBuffer::from(KNIGHT_FILE_NAME) }
+        if character.is_captain() { return ManagedBuffer::from(CAPTAIN_FILE_NAME) }
+        if character.is_general() { return ManagedBuffer::from(GENERAL_FILE_NAME) }
         sc_panic!("Invalid character rank {}.", character.rank);
     }
 
@@ -101,13 +136,27 @@ pub trait NftAttributesModule {
         tool
     }
 
+    /// Get the land object from the NFT attributes data
+    fn get_land(&self, owner_address: &ManagedAddress, land_collection_id: &TokenIdentifier, land_nonce: u64) -> Land {
+        // Get the land NFT data
+        let land_nft_data = self.blockchain().get_esdt_token_data(owner_address, land_collection_id, land_nonce);
+
+        // Get the NFT attributes
+        let nft_attributes = land_nft_data.attributes;
+        require!(!nft_attributes.is_empty(), "Cannot get land nonce {} NFT attributes. Is the NFT owner address correct?", land_nonce);
+
+        // Decode the NFT attributes
+        self.decode_land(nft_attributes)
+    }
+
 
     // Decode NFT attributes
 
 
     /// Decode the NFT attributes and return a Character object
+    /// The xp, level, class and origin are optional, characters minted before they were added have 0 xp, level 0, no class and a minted origin
     /// Ex: metadata:IPFS_CID/citizen.json;tags:character,citizen;c:0:0:0
-    /// Ex: metadata:IPFS_CID/soldier21.json;tags:character,soldier;c:1:2:1
+    /// Ex: metadata:IPFS_CID/soldier21.json;tags:character,soldier;c:1:2:1:130:1:2:1
     fn decode_character(&self, nft_attributes: ManagedBuffer) -> Character {
 
         // Character prefix
@@ -119,10 +168,18 @@ pub trait NftAttributesModule {
         let mut rank = 0u8;
         let mut attack = 0u8;
         let mut defence = 0u8;
+        let mut xp = 0u64;
+        let mut level = 0u8;
+        let mut class = CHARACTER_CLASS_NONE;
+        let mut origin = CHARACTER_ORIGIN_MINTED;
         let mut prefix_found = false;
         let mut in_rank = false;
         let mut in_attack = false;
         let mut in_defence = false;
+        let mut in_xp = false;
+        let mut in_level = false;
+        let mut in_class = false;
+        let mut in_origin = false;
 
         
         nft_attributes.for_each_batch::<BATCH_SIZE, _>(|batch| {
@@ -155,9 +212,47 @@ pub trait NftAttributesModule {
                         attack = attack * 10 + (batch[i] - b'0');
                     }
                 } else if in_defence {
-                    require!(batch[i].is_ascii_digit(), "Invalid defence format");
-                    // Parse the defence
-                    defence = defence * 10 + (batch[i] - b'0');
+                    if batch[i] == b':' {
+                        in_defence = false;
+                        in_xp = true;
+                    } else {
+                        require!(batch[i].is_ascii_digit(), "Invalid defence format");
+                        // Parse the defence
+                        defence = defence * 10 + (batch[i] - b'0');
+                    }
+                } else if in_xp {
+                    if batch[i] == b':' {
+                        in_xp = false;
+                        in_level = true;
+                    } else {
+                        require!(batch[i].is_ascii_digit(), "Invalid xp format");
+                        // Parse the xp, failing on overflow
+                        let parsed_xp = xp.checked_mul(10).and_then(|xp| xp.checked_add((batch[i] - b'0') as u64));
+                        require!(parsed_xp.is_some(), "Invalid xp format");
+                        xp = parsed_xp.unwrap();
+                    }
+                } else if in_level {
+                    if batch[i] == b':' {
+                        in_level = false;
+                        in_class = true;
+                    } else {
+                        require!(batch[i].is_ascii_digit(), "Invalid level format");
+                        // Parse the level
+                        level = level * 10 + (batch[i] - b'0');
+                    }
+                } else if in_class {
+                    if batch[i] == b':' {
+                        in_class = false;
+                        in_origin = true;
+                    } else {
+                        require!(batch[i].is_ascii_digit(), "Invalid class format");
+                        // Parse the class
+                        class = class * 10 + (batch[i] - b'0');
+                    }
+                } else if in_origin {
+                    require!(batch[i].is_ascii_digit(), "Invalid origin format");
+                    // Parse the origin
+                    origin = origin * 10 + (batch[i] - b'0');
                 }
                 i += 1;
             }
@@ -167,7 +262,7 @@ pub trait NftAttributesModule {
         require!(prefix_found, "Character attributes prefix not found");
 
         // Return the character
-        Character { rank, attack, defence }
+        Character { rank, attack, defence, xp, level, class, origin }
     }
 
 
@@ -235,4 +330,58 @@ pub trait NftAttributesModule {
         Tool { tool_type, attack, defence }
     }
 
-}
\ No newline at end of file
+
+    /// Decode the NFT attributes and return a Land object
+    /// Ex: tags:land,forest;l:1:1
+    /// Ex: tags:land,mine;l:4:3
+    fn decode_land(&self, nft_attributes: ManagedBuffer) -> Land {
+
+        // Land prefix
+        let prefix_len = NFT_LAND_ATTRIBUTES_PREFIX.len();
+        let prefix_bytes = NFT_LAND_ATTRIBUTES_PREFIX.as_bytes();
+
+        // Process attributes buffer
+        const BATCH_SIZE: usize = 256; // should be enough for one pass
+        let mut land_type = 0u8;
+        let mut tier = 0u8;
+        let mut prefix_found = false;
+        let mut in_land_type = false;
+        let mut in_tier = false;
+
+        nft_attributes.for_each_batch::<BATCH_SIZE, _>(|batch| {
+            let mut i = 0;
+            while i < batch.len() {
+                if !prefix_found {
+                    // Search for the land prefix is present
+                    if i + prefix_len <= batch.len() && &batch[i..i+prefix_len] == prefix_bytes {
+                        prefix_found = true;
+                        i += prefix_len;
+                        in_land_type = true;
+                        continue;
+                    }
+                } else if in_land_type {
+                    if batch[i] == b':' {
+                        in_land_type = false;
+                        in_tier = true;
+                    } else {
+                        require!(batch[i].is_ascii_digit(), "Invalid land type format");
+                        // Parse the land type
+                        land_type = land_type * 10 + (batch[i] - b'0');
+                    }
+                } else if in_tier {
+                    require!(batch[i].is_ascii_digit(), "Invalid tier format");
+                    // Parse the tier
+                    tier = tier * 10 + (batch[i] - b'0');
+                }
+                i += 1;
+            }
+        });
+
+        // Check if the prefix was found or return an error
+        require!(prefix_found, "Land attributes prefix not found");
+
+        // Return the land
+        Land { land_type, tier }
+    }
+
+}
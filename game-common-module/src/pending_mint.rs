@@ -0,0 +1,95 @@
+use multiversx_sc::imports::*;
+
+use crate::constants::{CANCEL_MINT_FEE_DEFAULT, MAX_CANCEL_MINT_FEE};
+
+#[multiversx_sc::module]
+pub trait PendingMintModule {
+
+    /// Set the fee kept when a pending mint is cancelled, in basis points
+    #[only_owner]
+    #[endpoint(setCancelMintFee)]
+    fn set_cancel_mint_fee(&self, cancel_mint_fee: u64) {
+        require!(cancel_mint_fee <= MAX_CANCEL_MINT_FEE, "Cancel mint fee must be at most {}.", MAX_CANCEL_MINT_FEE);
+        self.cancel_mint_fee().set(cancel_mint_fee);
+    }
+
+    /// Set the speed-up token (e.g., SNOW) and the price for each second cut from a pending mint
+    #[only_owner]
+    #[endpoint(setSpeedUpPrice)]
+    fn set_speed_up_price(&self, price_token_id: TokenIdentifier, price_per_second: BigUint) {
+        require!(price_per_second > 0, "Speed-up price must be greater than 0.");
+        self.speed_up_price().set(EsdtTokenPayment::new(price_token_id, 0, price_per_second));
+    }
+
+    /// Set the treasury address receiving the speed-up payments, if not specified the payments are burned
+    #[only_owner]
+    #[endpoint(setSpeedUpTreasury)]
+    fn set_speed_up_treasury(&self, treasury_address: OptionalValue<ManagedAddress>) {
+        match treasury_address {
+            OptionalValue::Some(address) => self.speed_up_treasury().set(address),
+            OptionalValue::None => self.speed_up_treasury().clear(),
+        }
+    }
+
+    /// Get the fee in basis points kept when a pending mint is cancelled
+    fn get_cancel_mint_fee(&self) -> u64 {
+        if self.cancel_mint_fee().is_empty() { CANCEL_MINT_FEE_DEFAULT }
+        else { self.cancel_mint_fee().get() }
+    }
+
+    /// Check the speed-up payment for the seconds cut from a pending mint, then burn it or send it to the treasury
+    /// Returns the seconds saved, all the remaining seconds if no seconds are specified
+    fn pay_speed_up(&self, remaining_seconds: u64, seconds: OptionalValue<u64>) -> u64 {
+        let seconds_saved = match seconds {
+            OptionalValue::Some(seconds) => core::cmp::min(seconds, remaining_seconds),
+            OptionalValue::None => remaining_seconds,
+        };
+        require!(seconds_saved > 0, "Seconds to cut must be greater than 0.");
+
+        // Check the payment for the seconds saved
+        let (token_id, payment_amount) = self.call_value().single_fungible_esdt();
+        let price = self.get_speed_up_payment(seconds_saved);
+        let price_token_id = price.token_identifier;
+        let price_amount = price.amount;
+        require!(
+            *token_id == price_token_id && *payment_amount == price_amount,
+            "Speeding up {} seconds costs {} {}.", seconds_saved, price_amount, price_token_id
+        );
+
+        // Burn the payment or send it to the treasury
+        if self.speed_up_treasury().is_empty() {
+            self.send().esdt_local_burn(&token_id, 0, &payment_amount);
+        } else {
+            self.send().direct_esdt(&self.speed_up_treasury().get(), &token_id, 0, &payment_amount);
+        }
+
+        seconds_saved
+    }
+
+    /// Get the speed-up token and amount to cut the given seconds from a pending mint
+    fn get_speed_up_payment(&self, seconds: u64) -> EsdtTokenPayment {
+        require!(!self.speed_up_price().is_empty(), "Speed-up price not set.");
+
+        let speed_up_price = self.speed_up_price().get();
+        EsdtTokenPayment::new(speed_up_price.token_identifier, 0, speed_up_price.amount * seconds)
+    }
+
+
+    // Storage
+
+    /// Fee kept when a pending mint is cancelled, in basis points
+    #[view(getCancelMintFee)]
+    #[storage_mapper("cancelMintFee")]
+    fn cancel_mint_fee(&self) -> SingleValueMapper<u64>;
+
+    /// Speed-up token and price for each second cut from a pending mint
+    #[view(getSpeedUpPrice)]
+    #[storage_mapper("speedUpPrice")]
+    fn speed_up_price(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    /// Treasury address receiving the speed-up payments, burned if not set
+    #[view(getSpeedUpTreasury)]
+    #[storage_mapper("speedUpTreasury")]
+    fn speed_up_treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+}
@@ -11,8 +11,14 @@ pub trait AdminModule:
     #[init]
     fn init(&self) {}
 
+    /// Clear the addresses of the single resource mint contracts, replaced by the resource mint contract address
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.legacy_wood_mint_contract_address().clear();
+        self.legacy_food_mint_contract_address().clear();
+        self.legacy_stone_mint_contract_address().clear();
+        self.legacy_gold_mint_contract_address().clear();
+    }
 
     /// Set character contract address
     #[only_owner]
@@ -42,32 +48,11 @@ pub trait AdminModule:
         self.game_arena_contract_address().set(address);
     }
 
-    /// Set wood mint contract address
-    #[only_owner]
-    #[endpoint(setWoodMintContractAddress)]
-    fn set_wood_mint_contract_address(&self, address: ManagedAddress) {
-        self.wood_mint_contract_address().set(address);
-    }
-
-    /// Set food mint contract address
-    #[only_owner]
-    #[endpoint(setFoodMintContractAddress)]
-    fn set_food_mint_contract_address(&self, address: ManagedAddress) {
-        self.food_mint_contract_address().set(address);
-    }
-
-    /// Set stone mint contract address
-    #[only_owner]
-    #[endpoint(setStoneMintContractAddress)]
-    fn set_stone_mint_contract_address(&self, address: ManagedAddress) {
-        self.stone_mint_contract_address().set(address);
-    }
-
-    /// Set gold mint contract address
+    /// Set resource mint contract address
     #[only_owner]
-    #[endpoint(setGoldMintContractAddress)]
-    fn set_gold_mint_contract_address(&self, address: ManagedAddress) {
-        self.gold_mint_contract_address().set(address);
+    #[endpoint(setResourceMintContractAddress)]
+    fn set_resource_mint_contract_address(&self, address: ManagedAddress) {
+        self.resource_mint_contract_address().set(address);
     }
 
 
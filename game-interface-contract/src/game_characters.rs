@@ -11,14 +11,18 @@ pub trait CharactersModule:
     game_common_module::GameCommonModule
 {
 
-    /// Calls the character contract to mint a citizen using resources from user's deposits
+    /// Calls the character contract to mint citizens using resources from user's deposits
+    /// by specifying the number of citizens to mint if more than one
     #[endpoint(mintCitizen)]
-    fn mint_citizen(&self) {
+    fn mint_citizen(&self, count: OptionalValue<u64>) {
         self.require_character_contract_address();
 
+        let count = count.into_option().unwrap_or(1u64);
+        require!(count > 0 && count <= MAX_MINT_CITIZEN_COUNT, "Count must be between 1 and {}.", MAX_MINT_CITIZEN_COUNT);
+
         // Get the wood and food required
-        let wood_quantity = BigUint::from(MINT_CITIZEN_WOOD_QUANTITY);
-        let food_quantity = BigUint::from(MINT_CITIZEN_FOOD_QUANTITY);
+        let wood_quantity = BigUint::from(MINT_CITIZEN_WOOD_QUANTITY) * count;
+        let food_quantity = BigUint::from(MINT_CITIZEN_FOOD_QUANTITY) * count;
 
         // Get the user deposits
         let user = self.blockchain().get_caller();
@@ -53,7 +57,7 @@ pub trait CharactersModule:
                     // Set the receiver address for the NFT to the user address
                     .argument(&user)
                     // Set the callback for updating deposit amounts if successful
-                    .with_callback(self.callbacks().mint_citizen_callback(&user, wood_deposit.token_id.clone(), food_deposit.token_id.clone()))
+                    .with_callback(self.callbacks().mint_citizen_callback(&user, wood_deposit.token_id.clone(), food_deposit.token_id.clone(), count))
                     .async_call_and_exit();
             },
         }
@@ -65,13 +69,14 @@ pub trait CharactersModule:
         user: &ManagedAddress, 
         wood_token: TokenIdentifier, 
         food_token: TokenIdentifier,
+        count: u64,
         #[call_result] result: ManagedAsyncCallResult<()>) {
 
         match result {
             ManagedAsyncCallResult::Ok(_) => {
                 // Get user deposits and update spent food and wood
-                self.decrease_deposit_balance_u64(user, &wood_token, 0, MINT_CITIZEN_WOOD_QUANTITY);
-                self.decrease_deposit_balance_u64(user, &food_token, 0, MINT_CITIZEN_FOOD_QUANTITY);
+                self.decrease_deposit_balance(user, &wood_token, 0, &(BigUint::from(MINT_CITIZEN_WOOD_QUANTITY) * count));
+                self.decrease_deposit_balance(user, &food_token, 0, &(BigUint::from(MINT_CITIZEN_FOOD_QUANTITY) * count));
             },
             ManagedAsyncCallResult::Err(_) => {
                 // If the transaction fails, deposits are not updated
@@ -79,26 +84,31 @@ pub trait CharactersModule:
         }
     }
 
-    /// Calls the character contract to claim a citizen after the minting period
+    /// Calls the character contract to claim the citizens after the minting period
+    /// by specifying the maximum number of citizens to claim if needed
     #[endpoint(claimCitizen)]
-    fn claim_citizen(&self) {
+    fn claim_citizen(&self, max_count: OptionalValue<usize>) {
         self.require_character_contract_address();
 
         let user = self.blockchain().get_caller();
-        // Call the character contract to mint the citizen and transfer the NFT to the user
-        self.tx()
+        // Call the character contract to mint the citizens and transfer the NFTs to the user
+        let mut tx = self.tx()
             .to(self.character_contract_address().get())
             .raw_call(CHARACTER_CONTRACT_CLAIM_CITIZEN_ENDPOINT_NAME)
-            // Claim the NFT to the user address
-            .argument(&user)
-            .async_call_and_exit();
+            // Claim the NFTs to the user address
+            .argument(&user);
+        if let Some(max_count) = max_count.into_option() {
+            tx = tx.argument(&max_count);
+        }
+        tx.async_call_and_exit();
     }
 
-    /// Calls the character contract to upgrade a citizen to a soldier
-    /// by specifying the NFT nonce and the NFT owner address if different than the caller
+    /// Calls the character contract to upgrade a citizen to a soldier of the chosen class
+    /// by specifying the NFT nonce, the class and the NFT owner address if different than the caller
     #[endpoint(upgradeCitizenToSoldier)]
     fn upgrade_citizen_to_soldier(&self, 
             citizen_nft_nonce: u64, 
+            class: u8,
             nft_owner_address: OptionalValue<ManagedAddress>) {
 
         self.require_character_contract_address();
@@ -139,6 +149,7 @@ pub trait CharactersModule:
                     .with_multi_token_transfer(payments)
                     .raw_call(CHARACTER_CONTRACT_UPGRADE_CITIZEN_TO_SOLDIER_ENDPOINT_NAME)
                     .argument(&citizen_nft_nonce)
+                    .argument(&class)
                     .argument(&nft_owner)
                     // Set the callback for updating deposit amounts if successful
                     .with_callback(self.callbacks().upgrade_citizen_callback(&user, gold_deposit.token_id.clone(), ore_deposit.token_id.clone()))
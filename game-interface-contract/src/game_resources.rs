@@ -9,67 +9,41 @@ pub trait ResourcesModule:
     game_common_module::GameCommonModule
 {
 
-    /// Calls the resource contracts to mint any available base resources
+    /// Calls the resource mint contract to mint any available base resources (WOOD, FOOD, STONE, GOLD)
+    /// The keeper reward received by this contract, if any, is sent to the calling user
     #[endpoint(mintResources)]
     fn mint_resources(&self){
+        self.require_resource_mint_contract_address();
 
-        // Mint any available wood resources
-        if !self.wood_mint_contract_address().is_empty() {
-            self.resource_contract_mint(self.wood_mint_contract_address().get());
-        }
-
-        // Mint any available food resources
-        if !self.food_mint_contract_address().is_empty() {
-            self.resource_contract_mint(self.food_mint_contract_address().get());
-        }
-
-        // Mint any available stone resources
-        if !self.stone_mint_contract_address().is_empty() {
-            self.resource_contract_mint(self.stone_mint_contract_address().get());
-        }
+        let user = self.blockchain().get_caller();
 
-        // Mint any available gold resources
-        if !self.gold_mint_contract_address().is_empty() {
-            self.resource_contract_mint(self.gold_mint_contract_address().get());
+        let keeper_rewards = self.resource_contract_mint(self.resource_mint_contract_address().get());
+        if !keeper_rewards.is_empty() {
+            self.send().direct_multi(&user, &keeper_rewards);
         }
-
     }
 
-    /// Calls the resource contracts to claim any unclaimed base resources
+    /// Calls the resource mint contract to claim all unclaimed base resources in one call
     #[endpoint(claimResources)]
     fn claim_resources(&self) {
+        self.require_resource_mint_contract_address();
 
         // Send the resources to the calling user
         let user = self.blockchain().get_caller();
 
-        // Claim any available wood resources
-        if !self.wood_mint_contract_address().is_empty() {
-            self.resource_contract_claim(self.wood_mint_contract_address().get(), &user);
-        }
-
-        // Claim any available food resources
-        if !self.food_mint_contract_address().is_empty() {
-            self.resource_contract_claim(self.food_mint_contract_address().get(), &user);
-        }
-
-        // Claim any available stone resources
-        if !self.stone_mint_contract_address().is_empty() {
-            self.resource_contract_claim(self.stone_mint_contract_address().get(), &user);
-        }
-
-        // Claim any available gold resources
-        if !self.gold_mint_contract_address().is_empty() {
-            self.resource_contract_claim(self.gold_mint_contract_address().get(), &user);
-        }
+        self.resource_contract_claim(self.resource_mint_contract_address().get(), &user);
     }
 
     /// Calls the resource contract to mint any available base resources
-    fn resource_contract_mint(&self, resource_contract_address: ManagedAddress) {
+    /// Returns the keeper reward sent back to this contract
+    fn resource_contract_mint(&self, resource_contract_address: ManagedAddress) -> ManagedVec<EsdtTokenPayment> {
         
-        self.tx()
+        let back_transfers = self.tx()
             .to(&resource_contract_address)
             .raw_call(RESOURCE_CONTRACT_MINT_RESOURCES_ENDPOINT_NAME)
+            .returns(ReturnsBackTransfers)
             .sync_call();
+        back_transfers.esdt_payments
     }
 
     /// Calls the resource contract to claim any available base resources
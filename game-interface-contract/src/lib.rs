@@ -38,15 +38,34 @@ pub trait GameInterfaceContract:
         // Add the received tokens to the user's deposits
         let user = self.blockchain().get_caller();
     
+        self.deposit_payments(&user, &payments);
+    }
+
+    /// Endpoint to deposit tokens in the game interface contract for a user
+    /// Used by other contracts to send tokens directly to the user deposits, e.g., the resource mint contract auto-claim
+    /// 
+    /// # Arguments
+    /// * `user` - User address to add the deposit for
+    #[payable]
+    #[endpoint(depositFor)]
+    fn deposit_for(&self, user: ManagedAddress) {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), ERR_NO_ESDT_TOKENS_RECEIVED);
+
+        self.deposit_payments(&user, &payments);
+    }
+
+    /// Add the received payments to the user's deposits
+    fn deposit_payments(&self, user: &ManagedAddress, payments: &ManagedVec<EsdtTokenPayment>) {
         for payment in payments.iter() {
             if payment.token_type() == EsdtTokenType::Fungible {
-                self.add_or_increase_deposit_balance(&user, &payment.token_identifier, 0, &payment.amount);
+                self.add_or_increase_deposit_balance(user, &payment.token_identifier, 0, &payment.amount);
             } 
             else 
             if payment.token_type() == EsdtTokenType::NonFungible {
                 if self.is_required_token(&payment.token_identifier, &self.characters_collection_id().get().into_managed_buffer()) ||
                     self.is_required_token(&payment.token_identifier, &self.tools_collection_id().get().into_managed_buffer()) {
-                    self.add_nft_deposit(&user, &payment.token_identifier, payment.token_nonce);
+                    self.add_nft_deposit(user, &payment.token_identifier, payment.token_nonce);
                 } else {
                     sc_panic!("Received NFT {} is not valid. Send only Character or Tool NFTs.", payment.token_identifier);
                 }
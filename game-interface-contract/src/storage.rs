@@ -30,25 +30,10 @@ pub trait StorageModule {
     #[storage_mapper("game_arena_contract_address")]
     fn game_arena_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
 
-    /// Address of the Wood mint contract
-    #[view(woodMintContractAddress)]
-    #[storage_mapper("wood_mint_contract_address")]
-    fn wood_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
-
-    /// Address of the Food mint contract
-    #[view(foodMintContractAddress)]
-    #[storage_mapper("food_mint_contract_address")]
-    fn food_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
-
-    /// Address of the Stone mint contract
-    #[view(stoneMintContractAddress)]
-    #[storage_mapper("stone_mint_contract_address")]
-    fn stone_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
-
-    /// Address of the Gold mint contract
-    #[view(goldMintContractAddress)]
-    #[storage_mapper("gold_mint_contract_address")]
-    fn gold_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+    /// Address of the resource mint contract (WOOD, FOOD, STONE, GOLD)
+    #[view(resourceMintContractAddress)]
+    #[storage_mapper("resource_mint_contract_address")]
+    fn resource_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
 
     /// Id of the characters collection
     #[view(charactersCollectionId)]
@@ -60,4 +45,18 @@ pub trait StorageModule {
     #[storage_mapper("tools_collection_id")]
     fn tools_collection_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
-}
\ No newline at end of file
+    // Addresses of the single resource mint contracts, cleared on upgrade
+
+    #[storage_mapper("wood_mint_contract_address")]
+    fn legacy_wood_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("food_mint_contract_address")]
+    fn legacy_food_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("stone_mint_contract_address")]
+    fn legacy_stone_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("gold_mint_contract_address")]
+    fn legacy_gold_mint_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+}
@@ -8,3 +8,8 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/game_interface_contract.scen.json");
 }
+
+#[test]
+fn mint_citizen_count_go() {
+    world().run("scenarios/mint_citizen_count.scen.json");
+}
@@ -12,3 +12,8 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/game_interface_contract.scen.json");
 }
+
+#[test]
+fn mint_citizen_count_rs() {
+    world().run("scenarios/mint_citizen_count.scen.json");
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           38
+// Endpoints:                           33
 // Async Callback:                       1
-// Total number of exported functions:  41
+// Total number of exported functions:  36
 
 #![no_std]
 
@@ -21,14 +21,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         deposit => deposit
+        depositFor => deposit_for
         setCharacterContractAddress => set_character_contract_address
         setResourceTransformContractAddress => set_resource_transform_contract_address
         setToolsContractAddress => set_tools_contract_address
         setGameArenaContractAddress => set_game_arena_contract_address
-        setWoodMintContractAddress => set_wood_mint_contract_address
-        setFoodMintContractAddress => set_food_mint_contract_address
-        setStoneMintContractAddress => set_stone_mint_contract_address
-        setGoldMintContractAddress => set_gold_mint_contract_address
+        setResourceMintContractAddress => set_resource_mint_contract_address
         setCharactersCollectionId => set_characters_collection
         setToolsCollectionId => set_tools_collection
         clearDeposits => clear_deposits
@@ -39,10 +37,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         resourceTransformContractAddress => resource_transform_contract_address
         toolsContractAddress => tools_contract_address
         gameArenaContractAddress => game_arena_contract_address
-        woodMintContractAddress => wood_mint_contract_address
-        foodMintContractAddress => food_mint_contract_address
-        stoneMintContractAddress => stone_mint_contract_address
-        goldMintContractAddress => gold_mint_contract_address
+        resourceMintContractAddress => resource_mint_contract_address
         charactersCollectionId => characters_collection_id
         toolsCollectionId => tools_collection_id
         mintCitizen => mint_citizen
@@ -0,0 +1,76 @@
+use multiversx_sc::imports::*;
+
+use game_common_module::constants::*;
+use game_common_module::data::*;
+
+
+#[multiversx_sc::module]
+pub trait AdminModule:
+    crate::storage::StorageModule {
+
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Register collection as dynamic NFT and set all roles
+    #[only_owner]
+    #[payable]
+    #[endpoint(registerLandsCollection)]
+    fn register_lands_collection(&self) {
+        require!(self.lands_nft_collection().is_empty(), "Lands collection already registered.");
+
+        let register_cost = self.call_value().egld();
+        require!(*register_cost == REGISTER_FEE, "Send 0.05 EGLD for the register cost.");
+        
+        self.tx()
+            .to(ESDTSystemSCAddress)
+            .with_egld_transfer(register_cost.clone_value())
+            .raw_call(REGISTER_AND_SET_ALL_ROLES_DYNAMIC_ENDPOINT_NAME)
+            .argument(&ManagedBuffer::from(LANDS_COLLECTION_NAME))
+            .argument(&ManagedBuffer::from(LANDS_COLLECTION_TICKER))
+            .argument(&ManagedBuffer::from("NFT"))
+            .callback(self.callbacks().register_lands_callback())
+            .async_call_and_exit();
+    }
+
+    /// Callback to update the contract state with the registered NFT token
+    #[callback]
+    fn register_lands_callback(
+        &self,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.lands_nft_collection().set_token_id(token_id);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                let returned = self.call_value().egld_or_single_esdt();
+                if returned.token_identifier.is_egld() && returned.amount > 0 {
+                    self.tx().to(ToCaller).egld(returned.amount).transfer();
+                }
+                self.lands_nft_collection().clear();
+            },
+        }
+    }
+
+    /// Set the token and amount paid to mint a land of the given type
+    /// E.g., WOOD for a Forest, FOOD for a Farm, STONE for a Quarry, GOLD for a Mine
+    #[only_owner]
+    #[endpoint(setLandMintPrice)]
+    fn set_land_mint_price(&self, land_type: u8, token_id: TokenIdentifier, amount: BigUint) {
+        require!(Land::is_valid_land_type(land_type), "Invalid land type {}.", land_type);
+        self.land_mint_price(land_type).set(EsdtTokenPayment::new(token_id, 0, amount));
+    }
+
+    /// Set the token and amount paid to upgrade a land to the given tier
+    /// Lands can't be upgraded to tiers without an upgrade price
+    #[only_owner]
+    #[endpoint(setLandUpgradePrice)]
+    fn set_land_upgrade_price(&self, tier: u8, token_id: TokenIdentifier, amount: BigUint) {
+        require!(tier > 1, "Upgrade tier must be greater than 1.");
+        self.land_upgrade_price(tier).set(EsdtTokenPayment::new(token_id, 0, amount));
+    }
+
+}
@@ -0,0 +1,240 @@
+#![no_std]
+
+#[allow(unused_imports)]
+use multiversx_sc::imports::*;
+
+pub mod admin;
+pub mod storage;
+
+use game_common_module::data::*;
+use game_common_module::constants::*;
+
+#[multiversx_sc::contract]
+pub trait LandContract: 
+    storage::StorageModule +
+    admin::AdminModule +
+    game_common_module::GameCommonModule +
+    game_common_module::nft_attributes::NftAttributesModule
+{
+
+    /// Mints a tier 1 Land NFT of the given type
+    /// 
+    /// # Arguments
+    /// * `land_type` - Land type: 1 Forest, 2 Farm, 3 Quarry, 4 Mine
+    /// * `receiver_address` - Receiver address optional, if not specified the caller address will be used
+    #[payable]
+    #[endpoint(mintLand)]
+    fn mint_land(&self, land_type: u8, receiver_address: OptionalValue<ManagedAddress>) {
+        require!(Land::is_valid_land_type(land_type), "Invalid land type {}.", land_type);
+        require!(!self.land_mint_price(land_type).is_empty(), "Land mint price not set for land type {}.", land_type);
+
+        // Check the payment matches the land mint price
+        let (token_id, payment_amount) = self.call_value().single_fungible_esdt();
+        let mint_price = self.land_mint_price(land_type).get();
+        let price_amount = mint_price.amount;
+        let price_token_id = mint_price.token_identifier;
+        require!(*token_id == price_token_id && *payment_amount == price_amount,
+            "Land mint costs {} {}.", price_amount, price_token_id);
+
+        // Determine the receiver address if one is specified
+        let user = match receiver_address {
+            OptionalValue::Some(address) => address,
+            OptionalValue::None => self.blockchain().get_caller(),
+        };
+
+        // Burn the payment
+        self.send().esdt_local_burn(&token_id, 0, &payment_amount);
+
+        // Mint the NFT
+        let nft_nonce = self.create_land_nft(&Land::new_land(land_type));
+
+        // Transfer the NFT to the user
+        self.send().direct_esdt(
+            &user,
+            &self.lands_nft_collection().get_token_id(),
+            nft_nonce,
+            &BigUint::from(1u64),
+        );
+    }
+
+    /// Upgrades a Land NFT to the next tier
+    /// Requires the Land NFT and the upgrade price for the next tier
+    /// 
+    /// # Arguments
+    /// * `receiver_address` - Receiver address optional, if not specified the caller address will be used
+    #[payable]
+    #[endpoint(upgradeLand)]
+    fn upgrade_land(&self, receiver_address: OptionalValue<ManagedAddress>) {
+        self.require_lands_collection();
+
+        let transfers = self.call_value().all_esdt_transfers();
+        require!(transfers.len() == 2, "Endpoint requires 2 transfers, a Land NFT and the upgrade price.");
+
+        let lands_collection_id = self.lands_nft_collection().get_token_id();
+        let mut land_nft_nonce = 0;
+        let mut upgrade_payment = EsdtTokenPayment::new(TokenIdentifier::from(""), 0, BigUint::zero());
+
+        // Check the NFT and payment received
+        for transfer in transfers.iter() {
+            if transfer.token_identifier == lands_collection_id {
+                land_nft_nonce = transfer.token_nonce;
+            } else {
+                upgrade_payment = transfer.clone();
+            }
+        }
+        require!(land_nft_nonce > 0, "No Land NFT received.");
+
+        // Get the land, the NFT is now owned by the SC
+        let mut land = self.get_land(&self.blockchain().get_sc_address(), &lands_collection_id, land_nft_nonce);
+        land.upgrade();
+        let new_tier = land.tier;
+
+        // Check the payment matches the upgrade price for the next tier
+        require!(!self.land_upgrade_price(new_tier).is_empty(), "Land can't be upgraded to tier {}.", new_tier);
+        let upgrade_price = self.land_upgrade_price(new_tier).get();
+        let price_amount = upgrade_price.amount;
+        let price_token_id = upgrade_price.token_identifier;
+        require!(upgrade_payment.token_identifier == price_token_id && upgrade_payment.amount == price_amount,
+            "Land upgrade to tier {} costs {} {}.", new_tier, price_amount, price_token_id);
+
+        // Burn the payment
+        self.send().esdt_local_burn(&upgrade_payment.token_identifier, 0, &upgrade_payment.amount);
+
+        // Upgrade the NFT
+        self.upgrade_land_nft(land_nft_nonce, &land);
+
+        // Determine the receiver address if one is specified
+        let user = match receiver_address {
+            OptionalValue::Some(address) => address,
+            OptionalValue::None => self.blockchain().get_caller(),
+        };
+
+        // Send the land NFT back to the user
+        self.send().direct_esdt(
+            &user,
+            &lands_collection_id,
+            land_nft_nonce,
+            &BigUint::from(1u64),
+        );
+    }
+
+    /// Creates a Land NFT
+    fn create_land_nft(&self, land: &Land) -> u64 {
+        self.require_lands_collection();
+
+        // Get the last minted NFT nonce
+        let last_minted_nft_nonce = self.get_last_minted_nft_nonce();
+
+        // Get the collection ID
+        let collection_id = self.lands_nft_collection().get_token_id();
+
+        // Set the amount to mint to 1 NFT
+        let amount = BigUint::from(1u64);
+
+        // Set the NFT name
+        let nft_name = sc_format!("{} {}",
+            self.get_land_nft_name(land),
+            &(last_minted_nft_nonce + 1));
+
+        // Set the royalties
+        let royalties = BigUint::from(LAND_NFT_ROYALTIES);
+
+        // Get the attributes
+        let attributes = self.get_land_nft_attributes(land);
+
+        // Get the attributes hash
+        let attributes_sha256 = self.crypto().sha256(&attributes);
+        let attributes_hash = attributes_sha256.as_managed_buffer();
+
+        // Mint the NFT
+        let nft_nonce = self.send()
+            .esdt_nft_create(
+                &collection_id, 
+                &amount, 
+                &nft_name, 
+                &royalties, 
+                attributes_hash, 
+                &attributes, 
+                &ManagedVec::new());
+
+        self.last_minted_nft_nonce().set(nft_nonce);
+
+        nft_nonce
+    }
+
+    /// Recreates a Land NFT with the upgraded land attributes
+    fn upgrade_land_nft(&self, land_nft_nonce: u64, land: &Land) {
+        // NFT name
+        let new_nft_name = sc_format!("{} {}",
+            self.get_land_nft_name(land),
+            land_nft_nonce);
+
+        // Set the royalties
+        let royalties = BigUint::from(LAND_NFT_ROYALTIES);
+
+        // Get new NFT attributes
+        let new_attributes = self.get_land_nft_attributes(land);
+
+        // Calculate new NFT attributes hash
+        let new_attributes_sha256 = self.crypto().sha256(&new_attributes);
+        let new_attributes_hash = new_attributes_sha256.as_managed_buffer();
+
+        // Recreate the NFT with the new attributes and send the transaction and wait for completion (sync call)
+        self.tx()
+            .to(self.blockchain().get_sc_address())
+            .raw_call(ESDT_METADATA_RECREATE_ENDPOINT_NAME)
+            .argument(&self.lands_nft_collection().get_token_id())
+            .argument(&land_nft_nonce)
+            .argument(&new_nft_name)
+            .argument(&royalties)
+            .argument(new_attributes_hash)
+            .argument(&new_attributes)
+            .sync_call();
+    }
+
+
+    // Common functions
+
+    /// Gets the attributes for the NFT
+    /// Encode nft attributes in the format: tags:{tag(s)}{PREFIX}{land_type}:{tier}
+    /// Ex: tags:land,forest;l:1:1
+    /// Ex: tags:land,mine;l:4:3
+    fn get_land_nft_attributes(&self, land: &Land) -> ManagedBuffer {
+        sc_format!("tags:{}{}{}:{}",
+            self.get_land_asset_tags(land),
+            ManagedBuffer::from(NFT_LAND_ATTRIBUTES_PREFIX),
+            land.land_type,
+            land.tier)
+    }
+
+    /// Get the NFT name based on the land, e.g., Forest T2
+    fn get_land_nft_name(&self, land: &Land) -> ManagedBuffer {
+        let land_name = 
+            if land.is_forest() { FOREST_NFT_NAME }
+            else if land.is_farm() { FARM_NFT_NAME }
+            else if land.is_quarry() { QUARRY_NFT_NAME }
+            else if land.is_mine() { MINE_NFT_NAME }
+            else { sc_panic!("Unknown land type {}.", land.land_type) };
+        sc_format!("{} T{}", ManagedBuffer::from(land_name), land.tier)
+    }
+
+    /// Get the asset tags based on the land
+    fn get_land_asset_tags(&self, land: &Land) -> ManagedBuffer {
+        if land.is_forest() { return ManagedBuffer::from(FOREST_NFT_TAGS) };
+        if land.is_farm() { return ManagedBuffer::from(FARM_NFT_TAGS) };
+        if land.is_quarry() { return ManagedBuffer::from(QUARRY_NFT_TAGS) };
+        if land.is_mine() { return ManagedBuffer::from(MINE_NFT_TAGS) };
+        sc_panic!("Unknown land type {}.", land.land_type);
+    }
+
+    /// Require that the lands collection is issued
+    fn require_lands_collection(&self) {
+        require!(!self.lands_nft_collection().is_empty(), "Lands collection not issued");
+    }
+
+    /// Get the last minted NFT nonce
+    fn get_last_minted_nft_nonce(&self) -> u64 {
+        if self.last_minted_nft_nonce().is_empty() { 0 } 
+        else { self.last_minted_nft_nonce().get() }
+    }
+}
@@ -0,0 +1,27 @@
+use multiversx_sc::imports::*;
+
+
+#[multiversx_sc::module]
+pub trait StorageModule {
+
+    /// Lands NFT collection
+    #[view(getLandsNftCollection)]
+    #[storage_mapper("lands_nft_collection")]
+    fn lands_nft_collection(&self) -> NonFungibleTokenMapper;
+
+    /// Token and amount paid to mint a land of each type
+    #[view(getLandMintPrice)]
+    #[storage_mapper("landMintPrice")]
+    fn land_mint_price(&self, land_type: u8) -> SingleValueMapper<EsdtTokenPayment>;
+
+    /// Token and amount paid to upgrade a land to each tier
+    #[view(getLandUpgradePrice)]
+    #[storage_mapper("landUpgradePrice")]
+    fn land_upgrade_price(&self, tier: u8) -> SingleValueMapper<EsdtTokenPayment>;
+
+    /// Last minted NFT nonce
+    #[view(getLastMintedNftNonce)]
+    #[storage_mapper("lastMintedNftNonce")]
+    fn last_minted_nft_nonce(&self) -> SingleValueMapper<u64>;
+
+}
@@ -0,0 +1,20 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    ScenarioWorld::vm_go()
+}
+
+#[test]
+fn empty_go() {
+    world().run("scenarios/land_contract.scen.json");
+}
+
+#[test]
+fn mint_land_go() {
+    world().run("scenarios/mint_land.scen.json");
+}
+
+#[test]
+fn upgrade_land_go() {
+    world().run("scenarios/upgrade_land.scen.json");
+}
@@ -0,0 +1,24 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    // blockchain.set_current_dir_from_workspace("relative path to your workspace, if applicable");
+    blockchain.register_contract("mxsc:output/land-contract.mxsc.json", land_contract::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn empty_rs() {
+    world().run("scenarios/land_contract.scen.json");
+}
+
+#[test]
+fn mint_land_rs() {
+    world().run("scenarios/mint_land.scen.json");
+}
+
+#[test]
+fn upgrade_land_rs() {
+    world().run("scenarios/upgrade_land.scen.json");
+}
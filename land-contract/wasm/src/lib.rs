@@ -0,0 +1,35 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            9
+// Async Callback:                       1
+// Total number of exported functions:  12
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    land_contract
+    (
+        init => init
+        upgrade => upgrade
+        mintLand => mint_land
+        upgradeLand => upgrade_land
+        getLandsNftCollection => lands_nft_collection
+        getLandMintPrice => land_mint_price
+        getLandUpgradePrice => land_upgrade_price
+        getLastMintedNftNonce => last_minted_nft_nonce
+        registerLandsCollection => register_lands_collection
+        setLandMintPrice => set_land_mint_price
+        setLandUpgradePrice => set_land_upgrade_price
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { land_contract }
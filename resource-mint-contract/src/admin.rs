@@ -1,21 +1,47 @@
 use multiversx_sc::imports::*;
 
 use crate::constants::*;
+use crate::data::*;
 
 /// Admin module to update contract parameters if needed
 #[multiversx_sc::module]
 pub trait AdminModule: crate::storage::StorageModule {
-    /// Set mint rounds interval
+    /// Add an accepted stake token, or update its weight if already added
+    /// The weight is set in basis points (e.g., 10000 for full weight, 5000 for half weight)
+    #[only_owner]
+    #[endpoint(addStakeToken)]
+    fn add_stake_token(&self, token_id: TokenIdentifier, weight: u64) {
+        self.insert_stake_token(token_id, weight);
+    }
+
+    /// Insert an accepted stake token with its weight in basis points
+    fn insert_stake_token(&self, token_id: TokenIdentifier, weight: u64) {
+        require!(token_id.is_valid_esdt_identifier(), ERR_INVALID_STAKE_TOKEN_ID);
+        require!(weight > 0, ERR_STAKE_TOKEN_WEIGHT_ZERO);
+        self.accepted_stake_tokens().insert(token_id, weight);
+    }
+
+    /// Remove an accepted stake token
+    /// Existing stakes of the token stop producing resources and can be unstaked
+    #[only_owner]
+    #[endpoint(removeStakeToken)]
+    fn remove_stake_token(&self, token_id: TokenIdentifier) {
+        require!(self.accepted_stake_tokens().remove(&token_id).is_some(), ERR_STAKE_TOKEN_NOT_FOUND);
+    }
+
+    /// Set mint rounds interval for the resource token
     #[only_owner]
     #[endpoint(setMintRoundsInterval)]
-    fn set_mint_rounds_interval(&self, mint_rounds: u64) {
-        self.mint_rounds_interval().set(mint_rounds);
+    fn set_mint_rounds_interval(&self, resource_token_id: TokenIdentifier, mint_rounds: u64) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        self.mint_rounds_interval(&resource_token_id).set(mint_rounds);
     }
     /// Set stake threshold for minting one resource token
     #[only_owner]
     #[endpoint(setStakeThreshold)]
-    fn set_stake_threshold(&self, stake_amount: BigUint) {
-        self.mint_stake_threshold().set(stake_amount);
+    fn set_stake_threshold(&self, resource_token_id: TokenIdentifier, stake_amount: BigUint) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        self.mint_stake_threshold(&resource_token_id).set(stake_amount);
     }
 
     /// Set option to mint resource tokens if user has claimed all previously minted resources
@@ -25,14 +51,206 @@ pub trait AdminModule: crate::storage::StorageModule {
         self.option_mint_if_claimed().set(mint_if_claimed);
     }
 
-    /// Issue token to be used for minting resources
+    /// Set number of rounds unstaked tokens are locked before they can be claimed
+    #[only_owner]
+    #[endpoint(setUnstakeCooldownRounds)]
+    fn set_unstake_cooldown_rounds(&self, cooldown_rounds: u64) {
+        self.unstake_cooldown_rounds().set(cooldown_rounds);
+    }
+
+    /// Set base storage capacity of unclaimed resources for each user, 0 for unlimited storage
+    #[only_owner]
+    #[endpoint(setBaseStorageCapacity)]
+    fn set_base_storage_capacity(&self, resource_token_id: TokenIdentifier, capacity: BigUint) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        self.base_storage_capacity(&resource_token_id).set(capacity);
+    }
+
+    /// Set storage capacity added by each storage upgrade and the upgrade price
+    /// The price can be paid in a resource token, burned if the contract has its burn role, or in any other fungible token (e.g., SNOW),
+    /// sent to the storage upgrade treasury
+    #[only_owner]
+    #[endpoint(setStorageUpgrade)]
+    fn set_storage_upgrade(&self, resource_token_id: TokenIdentifier, upgrade_capacity: BigUint, price_token_id: TokenIdentifier, price_amount: BigUint) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        require!(
+            self.can_burn_storage_upgrade_payment(&price_token_id) || !self.storage_upgrade_treasury().is_empty(),
+            ERR_STORAGE_UPGRADE_TREASURY_NOT_SET
+        );
+        self.storage_upgrade_capacity(&resource_token_id).set(upgrade_capacity);
+        self.storage_upgrade_price(&resource_token_id).set(EsdtTokenPayment::new(price_token_id, 0, price_amount));
+    }
+
+    /// Set the address receiving the storage upgrade payments in tokens the contract cannot burn
+    #[only_owner]
+    #[endpoint(setStorageUpgradeTreasury)]
+    fn set_storage_upgrade_treasury(&self, treasury_address: ManagedAddress) {
+        self.storage_upgrade_treasury().set(treasury_address);
+    }
+
+    /// Set share of the unclaimed resources lost every mint interval, in basis points (e.g., 100 for 1%)
+    #[only_owner]
+    #[endpoint(setStorageDecayRate)]
+    fn set_storage_decay_rate(&self, resource_token_id: TokenIdentifier, decay_rate: u64) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        require!(decay_rate <= MAX_BASIS_POINTS, ERR_STORAGE_DECAY_RATE_TOO_HIGH);
+        self.storage_decay_rate(&resource_token_id).set(decay_rate);
+    }
+
+    /// Add a harvest season with a yield multiplier in basis points for the resource token (e.g., 20000 for double yield)
+    /// The multiplier applies to mint intervals closing between the start round (inclusive) and the end round (exclusive)
+    /// The multiplier can't go below half yield nor over 5x yield
+    #[only_owner]
+    #[endpoint(addHarvestSeason)]
+    fn add_harvest_season(&self, resource_token_id: TokenIdentifier, start_round: u64, end_round: u64, multiplier: u64) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        require!((MIN_HARVEST_SEASON_MULTIPLIER..=MAX_HARVEST_SEASON_MULTIPLIER).contains(&multiplier), ERR_INVALID_HARVEST_SEASON_MULTIPLIER);
+
+        // Seasons can only be scheduled in the future, so already passed intervals are not affected
+        let current_round = self.blockchain().get_block_round();
+        require!(start_round > current_round && end_round > start_round, ERR_INVALID_HARVEST_SEASON_ROUNDS);
+
+        // Keep seasons ordered by start round and drop the ones already applied to minted intervals
+        let last_mint_round = self.last_resource_mint_round(&resource_token_id).get();
+        let mut seasons = ManagedVec::new();
+        let mut season_added = false;
+        for season in self.harvest_seasons(&resource_token_id).get().iter() {
+            require!(end_round <= season.start_round || start_round >= season.end_round, ERR_HARVEST_SEASON_OVERLAP);
+            if season.end_round <= last_mint_round {
+                continue;
+            }
+            if !season_added && start_round < season.start_round {
+                seasons.push(HarvestSeason { start_round, end_round, multiplier });
+                season_added = true;
+            }
+            seasons.push(season.clone());
+        }
+        if !season_added {
+            seasons.push(HarvestSeason { start_round, end_round, multiplier });
+        }
+
+        self.harvest_seasons(&resource_token_id).set(seasons);
+    }
+
+    /// Remove a harvest season that has not started yet
+    #[only_owner]
+    #[endpoint(removeHarvestSeason)]
+    fn remove_harvest_season(&self, resource_token_id: TokenIdentifier, start_round: u64) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        require!(start_round > self.blockchain().get_block_round(), ERR_HARVEST_SEASON_NOT_FOUND);
+
+        let mut seasons = self.harvest_seasons(&resource_token_id).get();
+        let index = seasons.iter().position(|season| season.start_round == start_round);
+        match index {
+            Some(index) => seasons.remove(index),
+            None => sc_panic!(ERR_HARVEST_SEASON_NOT_FOUND),
+        }
+
+        self.harvest_seasons(&resource_token_id).set(seasons);
+    }
+
+    /// Set keeper cut of the newly minted resources, in basis points (e.g., 100 for 1%)
+    #[only_owner]
+    #[endpoint(setKeeperRewardRate)]
+    fn set_keeper_reward_rate(&self, reward_rate: u64) {
+        require!(reward_rate <= MAX_KEEPER_REWARD_RATE, ERR_KEEPER_REWARD_RATE_TOO_HIGH);
+        self.keeper_reward_rate().set(reward_rate);
+    }
+
+    /// Set keeper bounty token (e.g., SNOW) and amount paid for each rewarded mint
+    #[only_owner]
+    #[endpoint(setKeeperBounty)]
+    fn set_keeper_bounty(&self, token_id: TokenIdentifier, amount: BigUint) {
+        // The bounty token can't be changed while the bounty is funded with another token
+        if !self.keeper_bounty().is_empty() && self.keeper_bounty_balance().get() > 0 {
+            require!(self.keeper_bounty().get().token_identifier == token_id, ERR_INVALID_KEEPER_BOUNTY_TOKEN);
+        }
+        self.keeper_bounty().set(EsdtTokenPayment::new(token_id, 0, amount));
+    }
+
+    /// Fund the keeper bounty balance with the keeper bounty token
+    #[only_owner]
+    #[payable]
+    #[endpoint(fundKeeperBounty)]
+    fn fund_keeper_bounty(&self) {
+        require!(!self.keeper_bounty().is_empty(), ERR_KEEPER_BOUNTY_NOT_SET);
+
+        let (token_id, amount) = self.call_value().single_fungible_esdt();
+        require!(*token_id == self.keeper_bounty().get().token_identifier, ERR_INVALID_KEEPER_BOUNTY_TOKEN);
+
+        self.keeper_bounty_balance().update(|balance| *balance += &*amount);
+    }
+
+    /// Set minimum number of rounds between keeper rewards
+    #[only_owner]
+    #[endpoint(setKeeperRewardCooldownRounds)]
+    fn set_keeper_reward_cooldown_rounds(&self, cooldown_rounds: u64) {
+        self.keeper_reward_cooldown_rounds().set(cooldown_rounds);
+    }
+
+    /// Set auto-claim destination contract and endpoint, e.g., the Game Interface Contract `depositFor` endpoint
+    /// The endpoint is called with the user address as argument and the user resources as payment
+    #[only_owner]
+    #[endpoint(setAutoClaimDestination)]
+    fn set_auto_claim_destination(&self, destination_address: ManagedAddress, endpoint_name: ManagedBuffer) {
+        self.auto_claim_destination().set(destination_address);
+        self.auto_claim_destination_endpoint().set(endpoint_name);
+    }
+
+    /// Set maximum number of auto-claim users processed by each autoClaimResources call
+    #[only_owner]
+    #[endpoint(setAutoClaimBatchSize)]
+    fn set_auto_claim_batch_size(&self, batch_size: usize) {
+        require!(batch_size > 0, ERR_AUTO_CLAIM_BATCH_SIZE_ZERO);
+        self.auto_claim_batch_size().set(batch_size);
+    }
+
+    /// Set the Land NFT collection accepted for land stakes
+    #[only_owner]
+    #[endpoint(setLandCollection)]
+    fn set_land_collection(&self, land_collection_id: TokenIdentifier) {
+        self.land_collection().set(land_collection_id);
+    }
+
+    /// Set the land type boosting the resource token production
+    /// Land types: 1 Forest, 2 Farm, 3 Quarry, 4 Mine
+    #[only_owner]
+    #[endpoint(setResourceLandType)]
+    fn set_resource_land_type(&self, resource_token_id: TokenIdentifier, land_type: u8) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
+        self.resource_land_type(&resource_token_id).set(land_type);
+    }
+
+    /// Set the yield multiplier for a land tier, in basis points (e.g., 12500 for +25% yield)
+    /// The multiplier can't reduce the production nor boost it over the maximum land tier multiplier
+    #[only_owner]
+    #[endpoint(setLandTierMultiplier)]
+    fn set_land_tier_multiplier(&self, tier: u8, multiplier: u64) {
+        require!(tier > 0, ERR_INVALID_LAND_TIER);
+        require!((MAX_BASIS_POINTS..=MAX_LAND_TIER_MULTIPLIER).contains(&multiplier), ERR_INVALID_LAND_TIER_MULTIPLIER);
+        self.land_tier_multiplier(tier).set(multiplier);
+    }
+
+    /// Issue a new token to be used for minting resources
+    /// Each resource token is minted with its own stake threshold and rounds interval
     #[only_owner]
     #[payable]
     #[endpoint(issueResourceToken)]
-    fn issue_resource_token(&self, token_name: ManagedBuffer, token_ticker: ManagedBuffer, initial_supply: OptionalValue<BigUint>) {
-        require!(self.resource_token_id().is_empty(), ERR_RESOURCE_TOKEN_ALREADY_ISSUED);
+    fn issue_resource_token(
+        &self,
+        token_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+        mint_stake_threshold: BigUint,
+        mint_rounds_interval: u64,
+        initial_supply: OptionalValue<BigUint>,
+    ) {
         require!(!token_name.is_empty(), ERR_TOKEN_NAME_EMPTY);
         require!(!token_ticker.is_empty(), ERR_TOKEN_TICKER_EMPTY);
+        require!(mint_stake_threshold > 0, ERR_MINT_STAKE_THRESHOLD_ZERO);
+        require!(mint_rounds_interval > 0, ERR_MINT_ROUNDS_INTERVAL_ZERO);
+        for resource_token_id in self.resource_tokens().iter() {
+            require!(resource_token_id.ticker() != token_ticker, ERR_RESOURCE_TOKEN_ALREADY_ISSUED);
+        }
 
         let payment = self.call_value().egld();
         let issue_cost = BigUint::from(ISSUE_FEE);
@@ -72,18 +290,25 @@ pub trait AdminModule: crate::storage::StorageModule {
                     can_add_special_roles: true,
                 },
             )
-            .with_callback(self.callbacks().issue_callback())
+            .with_callback(self.callbacks().issue_callback(mint_stake_threshold, mint_rounds_interval))
             .async_call_and_exit()
     }
 
     #[callback]
-    fn issue_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+    fn issue_callback(
+        &self,
+        mint_stake_threshold: BigUint,
+        mint_rounds_interval: u64,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
         match result {
             ManagedAsyncCallResult::Ok(_) => {
                 let (token_identifier, _returned_tokens) = 
                     self.call_value().single_fungible_esdt();
-                // Set resource token id
-                self.resource_token_id().set(token_identifier.clone());
+                // Add the resource token with its mint parameters
+                self.mint_stake_threshold(&token_identifier).set(mint_stake_threshold);
+                self.mint_rounds_interval(&token_identifier).set(mint_rounds_interval);
+                self.resource_tokens().insert(token_identifier.clone());
             },
             ManagedAsyncCallResult::Err(_) => {
                 let caller = self.blockchain().get_owner_address();
@@ -96,35 +321,44 @@ pub trait AdminModule: crate::storage::StorageModule {
         }
     }
 
-    /// Sets the local mint role for the resource token
+    /// Sets the local mint and burn roles for the resource token
+    /// The burn role is used for storage upgrades paid with the resource token
     #[only_owner]
     #[endpoint(setContractLocalMintRole)]
-    fn set_contract_local_mint_role(&self) {
-        require!(!self.resource_token_id().is_empty(), ERR_RESOURCE_TOKEN_NOT_ISSUED);
+    fn set_contract_local_mint_role(&self, resource_token_id: TokenIdentifier) {
+        require!(self.resource_tokens().contains(&resource_token_id), ERR_RESOURCE_TOKEN_NOT_ISSUED);
 
         // Send the set resource token local mint role transaction with callback
         self.send()
             .esdt_system_sc_tx()
             .set_special_roles(
-                &self.blockchain().get_sc_address(),
-                &self.resource_token_id().get(),
-                [EsdtLocalRole::Mint].iter().cloned(),
+                self.blockchain().get_sc_address(),
+                &resource_token_id,
+                [EsdtLocalRole::Mint, EsdtLocalRole::Burn].iter().cloned(),
             )
-            .with_callback(self.callbacks().resource_mint_role_callback())
+            .with_callback(self.callbacks().resource_mint_role_callback(&resource_token_id))
             .async_call_and_exit();
     }
 
     #[callback]
-    fn resource_mint_role_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+    fn resource_mint_role_callback(&self, resource_token_id: &TokenIdentifier, #[call_result] result: ManagedAsyncCallResult<()>) {
         match result {
             ManagedAsyncCallResult::Ok(()) => {
                 // Resource token has local mint role
-                self.contract_has_local_mint_role().set(true);
+                self.contract_has_local_mint_role(resource_token_id).set(true);
             },
             ManagedAsyncCallResult::Err(_) => {
                 // Resource token has no local mint role
-                self.contract_has_local_mint_role().set(false);
+                self.contract_has_local_mint_role(resource_token_id).set(false);
             },
         }
     }
+
+    /// Check if a storage upgrade payment token can be burned, a resource token the contract has the local burn role for
+    /// Deployments migrated from a single resource may only have the local mint role
+    fn can_burn_storage_upgrade_payment(&self, token_id: &TokenIdentifier) -> bool {
+        self.resource_tokens().contains(token_id) &&
+            self.blockchain().get_esdt_local_roles(token_id).has_role(&EsdtLocalRole::Burn)
+    }
+
 }
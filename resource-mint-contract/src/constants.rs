@@ -1,6 +1,15 @@
 // Constants
 pub const ISSUE_FEE: u64 = 50_000_000_000_000_000; // 0.05 EGLD
 pub const RESOURCE_TOKEN_DECIMALS: usize = 0; // In this case resources don't have decimals
+pub const RESOURCE_PRODUCTION_PRECISION: u64 = 1_000_000_000_000; // 10^12 fixed-point precision for fractional resources
+pub const MAX_BASIS_POINTS: u64 = 10_000; // 100%
+pub const AUTO_CLAIM_DESTINATION_GAS_LIMIT: u64 = 5_000_000; // Gas for each auto-claim destination call
+pub const AUTO_CLAIM_CALLBACK_GAS_LIMIT: u64 = 2_000_000; // Gas for each auto-claim destination callback
+pub const AUTO_CLAIM_BATCH_SIZE_DEFAULT: usize = 20; // Auto-claim users processed by each autoClaimResources call
+pub const MAX_KEEPER_REWARD_RATE: u64 = 1_000; // 10% of the newly minted resources
+pub const MAX_LAND_TIER_MULTIPLIER: u64 = 50_000; // 5x yield
+pub const MIN_HARVEST_SEASON_MULTIPLIER: u64 = 5_000; // half yield
+pub const MAX_HARVEST_SEASON_MULTIPLIER: u64 = 50_000; // 5x yield
 
 // Error messages
 pub const ERR_RESOURCE_TOKEN_ALREADY_ISSUED: &str = "Resource token already issued.";
@@ -12,6 +21,36 @@ pub const ERR_RESOURCE_TOKEN_NOT_ISSUED: &str = "Resource token not issued.";
 pub const ERR_CONTRACT_NO_MINT_ROLE: &str = "Contract does not have mint role.";
 pub const ERR_MINT_ROUNDS_INTERVAL_ZERO: &str = "Mint rounds interval must be greater than 0.";
 pub const ERR_MINT_STAKE_THRESHOLD_ZERO: &str = "Mint stake threshold must be greater than 0.";
-pub const ERR_STAKE_TOKEN_NOT_SET: &str = "Stake token not set.";
+pub const ERR_STAKE_TOKEN_NOT_SET: &str = "No stake tokens set.";
 pub const ERR_NO_ESDT_TOKENS_RECEIVED: &str = "No ESDT tokens received.";
 pub const ERR_INVALID_STAKE_TOKEN: &str = "Sent tokens are not valid for staking.";
+pub const ERR_INVALID_RESOURCE_TOKEN: &str = "Token is not a resource token of this contract.";
+pub const ERR_UNSTAKE_AMOUNT_ZERO: &str = "Unstake amount must be greater than 0.";
+pub const ERR_NO_STAKES_FOUND: &str = "No stakes found for the user.";
+pub const ERR_INSUFFICIENT_STAKE: &str = "Not enough tokens staked to unstake the requested amount.";
+pub const ERR_STORAGE_UPGRADE_NOT_SET: &str = "Storage capacity upgrade is not available for the resource token.";
+pub const ERR_STORAGE_UPGRADE_TREASURY_NOT_SET: &str = "Storage upgrade treasury is not set for payments in tokens the contract cannot burn.";
+pub const ERR_STORAGE_DECAY_RATE_TOO_HIGH: &str = "Storage decay rate must be at most 10000 basis points.";
+pub const ERR_INVALID_HARVEST_SEASON_ROUNDS: &str = "Harvest season must start in a future round and end after it starts.";
+pub const ERR_HARVEST_SEASON_OVERLAP: &str = "Harvest season overlaps an existing season.";
+pub const ERR_INVALID_HARVEST_SEASON_MULTIPLIER: &str = "Harvest season multiplier must be between 5000 and 50000 basis points.";
+pub const ERR_HARVEST_SEASON_NOT_FOUND: &str = "No upcoming harvest season found for the start round.";
+pub const ERR_KEEPER_REWARD_RATE_TOO_HIGH: &str = "Keeper reward rate must be at most 1000 basis points.";
+pub const ERR_KEEPER_BOUNTY_NOT_SET: &str = "Keeper bounty is not set.";
+pub const ERR_INVALID_KEEPER_BOUNTY_TOKEN: &str = "Invalid keeper bounty token.";
+pub const ERR_INVALID_STAKE_TOKEN_ID: &str = "Invalid stake token ID.";
+pub const ERR_STAKE_TOKEN_WEIGHT_ZERO: &str = "Stake token weight must be greater than 0.";
+pub const ERR_STAKE_TOKEN_NOT_FOUND: &str = "Stake token not found.";
+pub const ERR_AUTO_CLAIM_DESTINATION_NOT_SET: &str = "Auto-claim destination is not set.";
+pub const ERR_AUTO_CLAIM_WALLET_SMART_CONTRACT: &str = "Smart contracts can only auto-claim to the auto-claim destination.";
+pub const ERR_AUTO_CLAIM_BATCH_SIZE_ZERO: &str = "Auto-claim batch size must be greater than 0.";
+pub const ERR_LAND_COLLECTION_NOT_SET: &str = "Land collection is not set.";
+pub const ERR_RESOURCE_LAND_TYPE_NOT_SET: &str = "No land type boosts the resource token.";
+pub const ERR_INVALID_LAND_NFT: &str = "Send one Land NFT from the land collection.";
+pub const ERR_LAND_TYPE_MISMATCH: &str = "Land type does not match the resource token.";
+pub const ERR_LAND_ALREADY_STAKED: &str = "A land is already staked for the resource token.";
+pub const ERR_INVALID_LAND_TIER: &str = "Land tier must be greater than 0.";
+pub const ERR_INVALID_LAND_TIER_MULTIPLIER: &str = "Land tier multiplier must be between 10000 and 50000 basis points.";
+pub const ERR_NO_LAND_STAKED: &str = "No land staked for the resource token.";
+pub const ERR_NO_PENDING_UNSTAKES: &str = "No unstaked tokens pending to be claimed.";
+pub const ERR_LEGACY_RESOURCE_TOKEN_NOT_ISSUED: &str = "Stakes can't be migrated before the resource token is issued.";
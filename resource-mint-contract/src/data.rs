@@ -9,4 +9,51 @@ pub struct StakeInfo<M: ManagedTypeApi> {
     pub token: TokenIdentifier<M>,
     pub amount: BigUint<M>,
     pub round: u64,
+    pub resource: TokenIdentifier<M>,
+}
+
+/// Stake info structure of a single resource deployment, migrated on upgrade to the resource token it produces
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct LegacyStakeInfo<M: ManagedTypeApi> {
+    pub token: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub round: u64,
+}
+
+/// Unstake info structure for each pending unstake
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct UnstakeInfo<M: ManagedTypeApi> {
+    pub token: TokenIdentifier<M>,
+    pub amount: BigUint<M>,
+    pub unlock_round: u64,
+}
+
+/// Auto-claim option for each user
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy, Default)]
+pub enum AutoClaimOption {
+    #[default]
+    Disabled,
+    Wallet,
+    Destination,
+}
+
+/// Land stake structure for each user and resource token
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LandStake {
+    pub nonce: u64,
+    pub tier: u8,
+    pub round: u64,
+}
+
+/// Harvest season structure for scheduled resource yield multipliers
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct HarvestSeason {
+    pub start_round: u64,
+    pub end_round: u64,
+    pub multiplier: u64,
 }
\ No newline at end of file
@@ -16,48 +16,50 @@ use data::*;
 pub trait ResourceMintContract:
     storage::StorageModule +
     admin::AdminModule +
-    views::ViewsModule
+    views::ViewsModule +
+    game_common_module::nft_attributes::NftAttributesModule
 {
     /// Set up initial contract state
-    /// 
-    /// # Arguments
-    /// * `stake_token_ticker` - Stake token ticker
-    /// * `mint_stake_threshold` - Stake amount required to mint one resource
-    /// * `mint_rounds_interval` - Number of rounds between resource mints
+    /// Stake tokens are added with `addStakeToken`
+    /// Resource tokens, with their mint stake threshold and mint rounds interval, are added with `issueResourceToken`
     #[init]
-    fn init(&self, stake_token_ticker: ManagedBuffer, mint_stake_threshold: BigUint, mint_rounds_interval: u64) {
-        self.stake_token_ticker().set_if_empty(stake_token_ticker);
-        self.mint_stake_threshold().set_if_empty(mint_stake_threshold);
-        self.mint_rounds_interval().set_if_empty(mint_rounds_interval);
-    }
+    fn init(&self) {}
 
     /// Contract upgrade logic if necessary
+    /// The state of a single resource deployment is migrated to the resource token it produces
+    /// The given stake tokens are accepted with their weights, e.g., the legitimate stake tokens of the migrated stakes,
+    /// the stakes of any other token stop producing resources and can be unstaked
+    ///
+    /// # Arguments
+    /// * `stake_tokens` - Stake token IDs with their weight in basis points
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, stake_tokens: MultiValueEncoded<MultiValue2<TokenIdentifier, u64>>) {
+        self.migrate_single_resource_state();
+
+        for stake_token in stake_tokens {
+            let (token_id, weight) = stake_token.into_tuple();
+            self.insert_stake_token(token_id, weight);
+        }
+    }
 
     /// Endpoint for staking tokens
+    /// The stake is allocated to producing the specified resource
     /// 
     /// # Arguments
+    /// * `resource_token_id` - Resource token produced by the stake
     /// * `for_user` - User address optional, if not specified the caller address will be used
     #[payable]
     #[endpoint(stakeTokens)]
-    fn stake_tokens(&self, for_user: OptionalValue<ManagedAddress>) {
-        require!(!self.stake_token_ticker().is_empty(), ERR_STAKE_TOKEN_NOT_SET);
-        let stake_token_ticker = self.stake_token_ticker().get();
+    fn stake_tokens(&self, resource_token_id: TokenIdentifier, for_user: OptionalValue<ManagedAddress>) {
+        require!(!self.accepted_stake_tokens().is_empty(), ERR_STAKE_TOKEN_NOT_SET);
+        self.require_resource_token(&resource_token_id);
 
         let payments = self.call_value().all_esdt_transfers();
         require!(!payments.is_empty(), ERR_NO_ESDT_TOKENS_RECEIVED);
 
         // Check that all received tokens are stakeable
         for payment in payments.iter() {
-            let token_id = &payment.token_identifier;
-            let token_id_buffer = token_id.as_managed_buffer();
-
-            require!(
-                // Check that all received token IDs start with the specified stake token ticker
-                token_id_buffer.copy_slice(0, stake_token_ticker.len()).unwrap_or_default() == stake_token_ticker,
-                ERR_INVALID_STAKE_TOKEN
-            );
+            require!(self.accepted_stake_tokens().contains_key(&payment.token_identifier), ERR_INVALID_STAKE_TOKEN);
         }
 
         let user = match for_user {
@@ -77,43 +79,282 @@ pub trait ResourceMintContract:
                 token: token_id.clone(),
                 amount: amount.clone(),
                 round: current_round,
+                resource: resource_token_id.clone(),
             };
             user_stakes.push(stake_info);
         }
         self.stakes_info().insert(user, user_stakes);
     }
 
+    /// Endpoint for staking a Land NFT to boost the resource production
+    /// The land type must match the resource token, e.g., a Forest for WOOD. One land can be staked per resource token
+    /// 
+    /// # Arguments
+    /// * `resource_token_id` - Resource token boosted by the land
+    /// * `for_user` - User address optional, if not specified the caller address will be used
+    #[payable]
+    #[endpoint(stakeLand)]
+    fn stake_land(&self, resource_token_id: TokenIdentifier, for_user: OptionalValue<ManagedAddress>) {
+        require!(!self.land_collection().is_empty(), ERR_LAND_COLLECTION_NOT_SET);
+        self.require_resource_token(&resource_token_id);
+        require!(!self.resource_land_type(&resource_token_id).is_empty(), ERR_RESOURCE_LAND_TYPE_NOT_SET);
+
+        let payment = self.call_value().single_esdt();
+        let land_collection_id = self.land_collection().get();
+        require!(payment.token_identifier == land_collection_id && payment.amount == 1u64, ERR_INVALID_LAND_NFT);
+
+        let user = match for_user {
+            OptionalValue::Some(address) => address,
+            OptionalValue::None => self.blockchain().get_caller(),
+        };
+        require!(self.user_land_stake(&user, &resource_token_id).is_empty(), ERR_LAND_ALREADY_STAKED);
+
+        // Get the land, the NFT is now owned by the SC
+        let land = self.get_land(&self.blockchain().get_sc_address(), &land_collection_id, payment.token_nonce);
+        require!(land.land_type == self.resource_land_type(&resource_token_id).get(), ERR_LAND_TYPE_MISMATCH);
+
+        // Settle resources produced without the land up to this round
+        self.calculate_resources_to_mint(&resource_token_id);
+
+        self.user_land_stake(&user, &resource_token_id).set(LandStake {
+            nonce: payment.token_nonce,
+            tier: land.tier,
+            round: self.blockchain().get_block_round(),
+        });
+    }
+
+    /// Endpoint for unstaking a Land NFT
+    /// Resources accrued with the land are settled before the land is sent back
+    ///
+    /// # Arguments
+    /// * `resource_token_id` - Resource token boosted by the land
+    #[endpoint(unstakeLand)]
+    fn unstake_land(&self, resource_token_id: TokenIdentifier) {
+        let user = self.blockchain().get_caller();
+        require!(!self.user_land_stake(&user, &resource_token_id).is_empty(), ERR_NO_LAND_STAKED);
+
+        // Settle resources produced with the land up to this round
+        self.calculate_resources_to_mint(&resource_token_id);
+
+        let land_stake = self.user_land_stake(&user, &resource_token_id).take();
+        self.send().direct_esdt(&user, &self.land_collection().get(), land_stake.nonce, &BigUint::from(1u64));
+    }
+
+    /// Endpoint for unstaking tokens
+    /// Resources accrued by the current stakes are settled before the stake is reduced
+    /// If an unstake cooldown is set, the tokens can be claimed after the cooldown rounds have passed
+    ///
+    /// # Arguments
+    /// * `resource_token_id` - Resource token produced by the stake
+    /// * `token_id` - Staked token identifier
+    /// * `amount` - Amount of tokens to unstake
+    #[endpoint(unstakeTokens)]
+    fn unstake_tokens(&self, resource_token_id: TokenIdentifier, token_id: TokenIdentifier, amount: BigUint) {
+        require!(amount > BigUint::zero(), ERR_UNSTAKE_AMOUNT_ZERO);
+        self.require_resource_token(&resource_token_id);
+
+        let user = self.blockchain().get_caller();
+        let mut user_stakes = self.stakes_info().get(&user).unwrap_or_default();
+        require!(!user_stakes.is_empty(), ERR_NO_STAKES_FOUND);
+
+        // Check the user has enough of the token staked
+        let mut total_token_staked = BigUint::zero();
+        for stake in user_stakes.iter() {
+            if stake.token == token_id && stake.resource == resource_token_id {
+                total_token_staked += &stake.amount;
+            }
+        }
+        require!(total_token_staked >= amount, ERR_INSUFFICIENT_STAKE);
+
+        // Settle resources accrued with the current stakes up to the last closed interval
+        self.calculate_resources_to_mint(&resource_token_id);
+        let current_round = self.blockchain().get_block_round();
+        let last_mint_round = self.get_last_mint_round(&resource_token_id);
+
+        // Unstake from the latest stakes first, keeping the older stakes unchanged
+        // Each unstaked amount counts for the rounds it was staked in the current interval
+        let mut unstaked_stake_rounds = BigUint::zero();
+        let mut remaining_amount = amount.clone();
+        let mut index = user_stakes.len();
+        while index > 0 && remaining_amount > BigUint::zero() {
+            index -= 1;
+
+            let (stake_token, stake_amount, stake_round) = {
+                let stake = user_stakes.get(index);
+                if stake.token != token_id || stake.resource != resource_token_id {
+                    continue;
+                }
+                (stake.token.clone(), stake.amount.clone(), stake.round)
+            };
+
+            let staked_rounds = current_round - core::cmp::max(stake_round, last_mint_round);
+            unstaked_stake_rounds += core::cmp::min(&stake_amount, &remaining_amount) * staked_rounds;
+
+            if stake_amount <= remaining_amount {
+                // Remove the whole stake
+                remaining_amount -= &stake_amount;
+                user_stakes.remove(index);
+            } else {
+                // Reduce the stake amount
+                let stake_info = StakeInfo {
+                    token: stake_token,
+                    amount: stake_amount - &remaining_amount,
+                    round: stake_round,
+                    resource: resource_token_id.clone(),
+                };
+                let _ = user_stakes.set(index, stake_info);
+                remaining_amount = BigUint::zero();
+            }
+        }
+
+        // Update user stakes
+        if user_stakes.is_empty() {
+            self.stakes_info().remove(&user);
+        } else {
+            self.stakes_info().insert(user.clone(), user_stakes);
+        }
+
+        // Settle the production of the unstaked amount in the part of the current interval it was staked
+        let stake_token_weight = self.accepted_stake_tokens().get(&token_id).unwrap_or_default();
+        self.settle_partial_interval_production(&user, &resource_token_id, &(unstaked_stake_rounds * stake_token_weight));
+
+        let cooldown_rounds = self.unstake_cooldown_rounds().get();
+        if cooldown_rounds == 0 {
+            // Send the unstaked tokens back to the user
+            self.send().direct_esdt(&user, &token_id, 0, &amount);
+        } else {
+            // Lock the unstaked tokens until the cooldown has passed
+            let unstake_info = UnstakeInfo {
+                token: token_id,
+                amount,
+                unlock_round: self.blockchain().get_block_round() + cooldown_rounds,
+            };
+            let mut user_unstakes = self.pending_unstakes().get(&user).unwrap_or_default();
+            user_unstakes.push(unstake_info);
+            self.pending_unstakes().insert(user, user_unstakes);
+        }
+    }
+
+    /// Endpoint for claiming unstaked tokens after the cooldown has passed
+    #[endpoint(claimUnstakedTokens)]
+    fn claim_unstaked_tokens(&self) {
+        let user = self.blockchain().get_caller();
+        let user_unstakes = self.pending_unstakes().get(&user).unwrap_or_default();
+        require!(!user_unstakes.is_empty(), ERR_NO_PENDING_UNSTAKES);
+
+        let current_round = self.blockchain().get_block_round();
+        let mut still_locked: ManagedVec<UnstakeInfo<Self::Api>> = ManagedVec::new();
+        let mut unstakes_claimed = 0;
+
+        for unstake in user_unstakes.iter() {
+            // Check if the cooldown has passed
+            if current_round < unstake.unlock_round {
+                still_locked.push(unstake.clone());
+                continue;
+            }
+
+            self.send().direct_esdt(&user, &unstake.token, 0, &unstake.amount);
+            unstakes_claimed += 1;
+        }
+
+        if unstakes_claimed == 0 {
+            sc_panic!("{} unstake(s) still in the cooldown period.", user_unstakes.len());
+        }
+
+        // Update the user's pending unstakes
+        if still_locked.is_empty() {
+            self.pending_unstakes().remove(&user);
+        } else {
+            self.pending_unstakes().insert(user, still_locked);
+        }
+    }
+
     /// Endpoint for minting resources
+    /// Mints all resource tokens the contract has the local mint role for
+    /// The caller is rewarded as keeper for the resources settled by the mint, at most once per keeper reward cooldown
+    /// Resources already settled by other endpoints (e.g., unstakeTokens) are minted without a keeper reward
     #[endpoint(mintResources)]
     fn mint_resources(&self) {
-        require!(
-            !self.resource_token_id().is_empty(),
-            ERR_RESOURCE_TOKEN_NOT_ISSUED
-        );
-        require!(
-            self.contract_has_local_mint_role().get(),
-            ERR_CONTRACT_NO_MINT_ROLE
-        );
-        
-        // Calculate resources to mint
-        self.calculate_resources_to_mint();
-        let new_resources_to_mint = self.resources_to_mint().get();
+        require!(!self.resource_tokens().is_empty(), ERR_RESOURCE_TOKEN_NOT_ISSUED);
 
-        // Mint new resources if any
-        if new_resources_to_mint > BigUint::zero() {
-            let resource_token_id = self.resource_token_id().get();
-            let amount_to_mint = BigUint::from(new_resources_to_mint) * BigUint::from(10u64).pow(RESOURCE_TOKEN_DECIMALS as u32);
-            self.send().esdt_local_mint(
-                &resource_token_id,
-                0,
-                &amount_to_mint
-            );
+        let keeper = self.blockchain().get_caller();
+        let current_round = self.blockchain().get_block_round();
+        let keeper_reward_available = current_round >= self.last_keeper_reward_round().get() + self.keeper_reward_cooldown_rounds().get();
+        let keeper_reward_rate = self.keeper_reward_rate().get();
+        let mut keeper_rewards = ManagedVec::new();
+        let mut produced_resources = false;
+
+        for resource_token_id in self.resource_tokens().iter() {
+            // Resources without the mint role are minted once the role is set
+            if !self.contract_has_local_mint_role(&resource_token_id).get() {
+                continue;
+            }
+
+            // Calculate resources to mint
+            let settled_resources = self.calculate_resources_to_mint(&resource_token_id);
+            let mut new_resources_to_mint = self.resources_to_mint(&resource_token_id).get();
+
+            // Add the keeper cut of the resources settled by this mint on top of the resources produced for the users
+            if settled_resources > BigUint::zero() {
+                produced_resources = true;
+                if keeper_reward_available && keeper_reward_rate > 0 {
+                    let keeper_cut = &settled_resources * keeper_reward_rate / MAX_BASIS_POINTS;
+                    if keeper_cut > BigUint::zero() {
+                        new_resources_to_mint += &keeper_cut;
+                        let keeper_cut_amount = keeper_cut * BigUint::from(10u64).pow(RESOURCE_TOKEN_DECIMALS as u32);
+                        keeper_rewards.push(EsdtTokenPayment::new(resource_token_id.clone(), 0, keeper_cut_amount));
+                    }
+                }
+            }
+
+            // Decayed resources are already held or pending in the contract, so they are used to cover new resources first
+            let decayed_resources = self.decayed_resources(&resource_token_id).get();
+            if decayed_resources > BigUint::zero() {
+                if new_resources_to_mint >= decayed_resources {
+                    new_resources_to_mint -= &decayed_resources;
+                    self.decayed_resources(&resource_token_id).clear();
+                } else {
+                    self.decayed_resources(&resource_token_id).set(decayed_resources - &new_resources_to_mint);
+                    new_resources_to_mint = BigUint::zero();
+                }
+                self.resources_to_mint(&resource_token_id).clear();
+            }
 
-            self.resources_to_mint().clear();
+            // Mint new resources if any
+            if new_resources_to_mint > BigUint::zero() {
+                let amount_to_mint = new_resources_to_mint * BigUint::from(10u64).pow(RESOURCE_TOKEN_DECIMALS as u32);
+                self.send().esdt_local_mint(
+                    &resource_token_id,
+                    0,
+                    &amount_to_mint
+                );
+
+                self.resources_to_mint(&resource_token_id).clear();
+            }
+        }
+
+        // Reward the keeper only for mints that settled resources
+        if !keeper_reward_available || !produced_resources {
+            return;
+        }
+        self.last_keeper_reward_round().set(current_round);
+
+        // Add the keeper bounty if set and funded
+        if !self.keeper_bounty().is_empty() {
+            let keeper_bounty = self.keeper_bounty().get();
+            if keeper_bounty.amount > 0 && self.keeper_bounty_balance().get() >= keeper_bounty.amount {
+                self.keeper_bounty_balance().update(|balance| *balance -= &keeper_bounty.amount);
+                keeper_rewards.push(keeper_bounty);
+            }
+        }
+
+        if !keeper_rewards.is_empty() {
+            self.send().direct_multi(&keeper, &keeper_rewards);
         }
     }
 
     /// Endpoint for claiming resources
+    /// All available resources are sent to the user in one transfer
     /// 
     /// # Arguments
     /// * `for_user` - User address optional, if not specified the caller address will be used
@@ -125,115 +366,335 @@ pub trait ResourceMintContract:
             OptionalValue::None => self.blockchain().get_caller(),
         };
 
-        let user_available = self.user_unclaimed_resources(&user);
+        let payments = self.take_user_unclaimed_resources(&user);
 
-        // Send any available resources to the caller
-        if user_available > BigUint::zero() {
-            let resource_token_id = self.resource_token_id().get();
-            self.send().direct_esdt(
-                &user,
-                &resource_token_id,
-                0,
-                &user_available
-            );
-            // Update user state
-            self.user_has_unclaimed_resources(&user).set(false);
-            let user_claimed = self.user_claimed_resources(&user).get();
-            self.user_claimed_resources(&user).set(user_claimed + user_available);
+        // Send any available resources to the user
+        if !payments.is_empty() {
+            self.send().direct_multi(&user, &payments);
+        }
+    }
+
+    /// Endpoint for opting in or out of auto-claim
+    /// Users opted in receive their resources with the autoClaimResources endpoint, to their wallet or to the configured auto-claim destination
+    /// Smart contracts can only opt in for the auto-claim destination, as a transfer to a non-payable contract would fail the auto-claim batch
+    ///
+    /// # Arguments
+    /// * `auto_claim` - Auto-claim option: Disabled, Wallet or Destination
+    #[endpoint(setAutoClaim)]
+    fn set_auto_claim(&self, auto_claim: AutoClaimOption) {
+        let user = self.blockchain().get_caller();
+
+        match auto_claim {
+            AutoClaimOption::Disabled => {
+                self.auto_claim_users().swap_remove(&user);
+                self.user_auto_claim(&user).clear();
+            },
+            _ => {
+                if auto_claim == AutoClaimOption::Destination {
+                    require!(!self.auto_claim_destination().is_empty(), ERR_AUTO_CLAIM_DESTINATION_NOT_SET);
+                } else {
+                    require!(!self.blockchain().is_smart_contract(&user), ERR_AUTO_CLAIM_WALLET_SMART_CONTRACT);
+                }
+                self.auto_claim_users().insert(user.clone());
+                self.user_auto_claim(&user).set(auto_claim);
+            },
+        }
+    }
+
+    /// Endpoint for sending the unclaimed resources of the next batch of users opted in for auto-claim
+    /// The users are processed in turns from the auto-claim cursor, so the gas used by each call is bounded by the batch size
+    /// Kept out of mintResources, as the auto-claim destination promises cannot be registered when mintResources is called synchronously by another contract (e.g., the Game Interface Contract)
+    #[endpoint(autoClaimResources)]
+    fn auto_claim_resources(&self) {
+        let users_count = self.auto_claim_users().len();
+        if users_count == 0 {
+            return;
+        }
+        let batch_size = core::cmp::min(self.get_auto_claim_batch_size(), users_count);
+
+        let mut user_index = self.auto_claim_cursor().get();
+        for _ in 0..batch_size {
+            // Continue from the first user after the last one
+            user_index = if user_index >= users_count { 1 } else { user_index + 1 };
+            let user = self.auto_claim_users().get_by_index(user_index);
+
+            let payments = self.take_user_unclaimed_resources(&user);
+            if payments.is_empty() {
+                continue;
+            }
+
+            // Send to the auto-claim destination for the user, e.g., the Game Interface Contract deposit, or to the user wallet
+            if self.user_auto_claim(&user).get() == AutoClaimOption::Destination && !self.auto_claim_destination().is_empty() {
+                // The claim is restored by the callback if the destination call fails and the resources are returned
+                self.tx()
+                    .to(self.auto_claim_destination().get())
+                    .gas(AUTO_CLAIM_DESTINATION_GAS_LIMIT)
+                    .raw_call(self.auto_claim_destination_endpoint().get())
+                    .argument(&user)
+                    .payment(&payments)
+                    .callback(ResourceMintContract::callbacks(self).auto_claim_destination_callback(&user, &payments))
+                    .gas_for_callback(AUTO_CLAIM_CALLBACK_GAS_LIMIT)
+                    .register_promise();
+            } else {
+                self.send().direct_multi(&user, &payments);
+            }
+        }
+        self.auto_claim_cursor().set(user_index);
+    }
+
+    /// Endpoint for upgrading the user storage capacity for a resource token
+    /// The upgrade price is paid in the configured token, burned if it's a resource token or sent to the storage upgrade treasury otherwise (e.g., SNOW)
+    ///
+    /// # Arguments
+    /// * `resource_token_id` - Resource token to upgrade the storage capacity for
+    #[payable]
+    #[endpoint(upgradeStorageCapacity)]
+    fn upgrade_storage_capacity(&self, resource_token_id: TokenIdentifier) {
+        self.require_resource_token(&resource_token_id);
+        require!(!self.storage_upgrade_price(&resource_token_id).is_empty(), ERR_STORAGE_UPGRADE_NOT_SET);
+
+        let (token_id, payment_amount) = self.call_value().single_fungible_esdt();
+        let upgrade_price = self.storage_upgrade_price(&resource_token_id).get();
+        let price_amount = upgrade_price.amount;
+        let price_token_id = upgrade_price.token_identifier;
+        require!(
+            *token_id == price_token_id && *payment_amount == price_amount,
+            "Storage upgrade costs {} {}.", price_amount, price_token_id
+        );
+
+        // Settle resources produced with the current capacity up to this round
+        self.calculate_resources_to_mint(&resource_token_id);
+
+        // Upgrade the user storage capacity level
+        let user = self.blockchain().get_caller();
+        self.user_storage_level(&user, &resource_token_id).update(|level| *level += 1);
+
+        // Burn the payment if it's a resource token the contract can burn, otherwise send it to the treasury
+        if self.can_burn_storage_upgrade_payment(&token_id) {
+            self.send().esdt_local_burn(&token_id, 0, &payment_amount);
+        } else {
+            require!(!self.storage_upgrade_treasury().is_empty(), ERR_STORAGE_UPGRADE_TREASURY_NOT_SET);
+            self.send().direct_esdt(&self.storage_upgrade_treasury().get(), &token_id, 0, &payment_amount);
+        }
+    }
+
+    /// Update the user state for claiming all unclaimed resources and return them as payments
+    fn take_user_unclaimed_resources(&self, user: &ManagedAddress) -> ManagedVec<EsdtTokenPayment<Self::Api>> {
+        let mut payments: ManagedVec<EsdtTokenPayment<Self::Api>> = ManagedVec::new();
+
+        for resource_token_id in self.resource_tokens().iter() {
+            let user_available = self.user_unclaimed_resources(user, &resource_token_id);
+
+            if user_available > BigUint::zero() {
+                // Update user state
+                self.user_has_unclaimed_resources(user, &resource_token_id).set(false);
+                self.user_claimed_resources(user, &resource_token_id).update(|claimed| *claimed += &user_available);
+
+                payments.push(EsdtTokenPayment::new(resource_token_id, 0, user_available));
+            }
+        }
+        payments
+    }
+
+    /// Settle the user production of weighted stake amounts leaving the current mint interval before it closes
+    /// The weighted stake rounds are the sum of each weighted amount multiplied by the rounds it was staked in the interval,
+    /// counted pro rata to the interval rounds with the same multipliers and storage limit as a whole interval
+    fn settle_partial_interval_production(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier, weighted_stake_rounds: &BigUint) {
+        if *weighted_stake_rounds == 0u64 {
+            return;
+        }
+        // Skip users that have unclaimed resources if option is set
+        if self.option_mint_if_claimed().get() && self.user_has_unclaimed_resources(user, resource_token_id).get() {
+            return;
+        }
+
+        let mint_rounds_interval = self.mint_rounds_interval(resource_token_id).get();
+        let mint_stake_threshold = self.mint_stake_threshold(resource_token_id).get();
+        let last_mint_round = self.get_last_mint_round(resource_token_id);
+
+        // Skip users with a full storage
+        let user_storage_capacity = self.user_storage_capacity(user, resource_token_id);
+        let user_unclaimed = self.user_unclaimed_resources(user, resource_token_id);
+        if user_storage_capacity > 0 && user_unclaimed >= user_storage_capacity {
+            return;
+        }
+
+        // Production of the stake for the rounds staked in the interval, added to the fraction carried over
+        let end_mint_round = last_mint_round + mint_rounds_interval;
+        let harvest_multiplier = self.get_harvest_multiplier(resource_token_id, end_mint_round);
+        let land_multiplier = self.get_land_multiplier(user, resource_token_id, end_mint_round);
+        let precision = BigUint::from(RESOURCE_PRODUCTION_PRECISION);
+        let user_production = weighted_stake_rounds * &precision * harvest_multiplier * land_multiplier
+            / (mint_stake_threshold * MAX_BASIS_POINTS * MAX_BASIS_POINTS * MAX_BASIS_POINTS * mint_rounds_interval)
+            + self.user_resource_progress(user, resource_token_id).get();
+
+        let mut user_resources = &user_production / &precision;
+        let mut user_progress = user_production % &precision;
+        if user_storage_capacity > 0 && &user_unclaimed + &user_resources > user_storage_capacity {
+            user_resources = user_storage_capacity - user_unclaimed;
+            user_progress = BigUint::zero();
+        }
+        self.user_resource_progress(user, resource_token_id).set(user_progress);
+
+        if user_resources > BigUint::zero() {
+            self.user_minted_resources(user, resource_token_id).update(|minted| *minted += &user_resources);
+            self.user_has_unclaimed_resources(user, resource_token_id).set(true);
+            self.resources_to_mint(resource_token_id).update(|resources| *resources += user_resources);
+        }
+    }
+
+    /// Callback for the auto-claim destination call
+    /// If the call failed, the resources are back in the contract and the user claim is restored, so they can be claimed again
+    #[promises_callback]
+    fn auto_claim_destination_callback(
+        &self,
+        user: &ManagedAddress,
+        payments: &ManagedVec<EsdtTokenPayment<Self::Api>>,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Err(_) = result {
+            for payment in payments.iter() {
+                self.user_claimed_resources(user, &payment.token_identifier).update(|claimed| *claimed -= &payment.amount);
+                self.user_has_unclaimed_resources(user, &payment.token_identifier).set(true);
+            }
+        }
+    }
+
+    /// Get the maximum number of auto-claim users processed by each autoClaimResources call
+    fn get_auto_claim_batch_size(&self) -> usize {
+        if self.auto_claim_batch_size().is_empty() {
+            AUTO_CLAIM_BATCH_SIZE_DEFAULT
+        } else {
+            self.auto_claim_batch_size().get()
         }
     }
 
     /// Calculate resources to mint based on stake and rounds passed
-    fn calculate_resources_to_mint(&self) { 
-        require!(self.mint_rounds_interval().get() > 0, ERR_MINT_ROUNDS_INTERVAL_ZERO);
-        require!(self.mint_stake_threshold().get() > 0, ERR_MINT_STAKE_THRESHOLD_ZERO);
+    /// Returns the resources settled by the call, for the mint intervals closed since the last calculation
+    fn calculate_resources_to_mint(&self, resource_token_id: &TokenIdentifier) -> BigUint {
+        require!(self.mint_rounds_interval(resource_token_id).get() > 0, ERR_MINT_ROUNDS_INTERVAL_ZERO);
+        require!(self.mint_stake_threshold(resource_token_id).get() > 0, ERR_MINT_STAKE_THRESHOLD_ZERO);
 
         let current_round = self.blockchain().get_block_round();
-        let mint_rounds_interval = self.mint_rounds_interval().get();
-        let mint_stake_threshold = self.mint_stake_threshold().get();
-        let last_mint_round = self.get_last_mint_round();
+        let mint_rounds_interval = self.mint_rounds_interval(resource_token_id).get();
+        let mint_stake_threshold = self.mint_stake_threshold(resource_token_id).get();
+        let last_mint_round = self.get_last_mint_round(resource_token_id);
+        let storage_decay_rate = self.storage_decay_rate(resource_token_id).get();
 
         let mut new_resources_to_mint = BigUint::zero();
+        let mut new_decayed_resources = BigUint::zero();
         let mut latest_mint_round = last_mint_round;
-        let mut start_mint_round = last_mint_round;
-        let mut end_mint_round = start_mint_round + mint_rounds_interval;
+        let mut end_mint_round = last_mint_round + mint_rounds_interval;
         
         // Iterate through all potential passed rounds intervals while not passing the current round
         // This makes sure we can call calculate at any time with the same result
         while end_mint_round <= current_round {
-            for user in self.stakes_info().keys() {
+            // Harvest season multiplier applied to the interval, based on the interval closing round
+            let harvest_multiplier = self.get_harvest_multiplier(resource_token_id, end_mint_round);
 
-                // Skip users that have unclaimed resources if option is set
-                if self.option_mint_if_claimed().get() && self.user_has_unclaimed_resources(&user).get() {
-                    continue;
-                }
+            for user in self.stakes_info().keys() {
 
-                // Iterate through total user stake amount per mint round interval
-                let mut total_user_stake_amount = BigUint::from(0u64);
-                let user_stakes = self.stakes_info().get(&user).unwrap_or_default();
-                for stake in user_stakes.iter() {
-                    if stake.round < end_mint_round {
-                        total_user_stake_amount += stake.amount.clone();
+                // Decay a share of the user unclaimed resources if a decay rate is set
+                if storage_decay_rate > 0 {
+                    let user_decayed = self.user_unclaimed_resources(&user, resource_token_id) * storage_decay_rate / MAX_BASIS_POINTS;
+                    if user_decayed > BigUint::zero() {
+                        new_decayed_resources += &user_decayed;
+                        self.user_decayed_resources(&user, resource_token_id).update(|decayed| *decayed += user_decayed);
                     }
                 }
 
-                let stake_amount = total_user_stake_amount.clone();
-                let stake_per_resource = mint_stake_threshold.clone();
-
-                // Calculate resources to mint based on stake amount
-                if stake_amount >= stake_per_resource {
-                    // Round (total stake / stake amount per resource) to get number of resources to mint
-                    let user_resources_to_mint = BigUint::from((stake_amount / stake_per_resource).to_u64().unwrap_or_default());
-                    if user_resources_to_mint > BigUint::zero() {
-                        // Add to total new resources to mint
-                        new_resources_to_mint += user_resources_to_mint.clone();
-                        // Add to user state with new minted resources
-                        let user_minted = self.user_minted_resources(&user).get();
-                        let total_user_minted = user_minted + user_resources_to_mint;
-                        self.user_minted_resources(&user).set(total_user_minted);
-                    }
+                // Calculate user production for the interval, with the same logic used by the projection views
+                let user_production = self.calculate_user_interval_production(&user, resource_token_id, end_mint_round, &mint_stake_threshold, harvest_multiplier);
+                let (user_resources_to_mint, user_progress) = match user_production {
+                    Some(user_production) => user_production,
+                    None => continue,
+                };
+                self.user_resource_progress(&user, resource_token_id).set(user_progress);
+
+                if user_resources_to_mint > BigUint::zero() {
+                    // Add to total new resources to mint
+                    new_resources_to_mint += user_resources_to_mint.clone();
+                    // Add to user state with new minted resources
+                    let user_minted = self.user_minted_resources(&user, resource_token_id).get();
+                    let total_user_minted = user_minted + user_resources_to_mint;
+                    self.user_minted_resources(&user, resource_token_id).set(total_user_minted);
                 }
             }
             // Move to next round interval
             latest_mint_round = end_mint_round;
-            start_mint_round += mint_rounds_interval;
             end_mint_round += mint_rounds_interval;
         }
 
         // Update state
         if latest_mint_round > last_mint_round {
-            self.last_resource_mint_round().set(latest_mint_round);
+            self.last_resource_mint_round(resource_token_id).set(latest_mint_round);
 
             // Update users unclaimed resources flag
             for user in self.stakes_info().keys() {
-                if self.user_unclaimed_resources(&user) > BigUint::zero() {
-                    self.user_has_unclaimed_resources(&user).set(true);
+                if self.user_unclaimed_resources(&user, resource_token_id) > BigUint::zero() {
+                    self.user_has_unclaimed_resources(&user, resource_token_id).set(true);
                 }
             }
         }
+        // Update decayed resources, to be deducted from the minting of new resource tokens
+        if new_decayed_resources > BigUint::zero() {
+            self.decayed_resources(resource_token_id).update(|decayed| *decayed += new_decayed_resources);
+        }
         // Update resources to mint, to be used in the minting of new resource tokens
-        let unminted_resources = if !self.resources_to_mint().is_empty() { self.resources_to_mint().get() } else { BigUint::zero() };
-        self.resources_to_mint().set(unminted_resources + new_resources_to_mint);
+        let unminted_resources = if !self.resources_to_mint(resource_token_id).is_empty() { self.resources_to_mint(resource_token_id).get() } else { BigUint::zero() };
+        self.resources_to_mint(resource_token_id).set(unminted_resources + &new_resources_to_mint);
+
+        new_resources_to_mint
     }
 
 
-    /// Get last mint round
-    fn get_last_mint_round(&self) -> u64 {
-        // If no previous mint round is set initially
-        if self.last_resource_mint_round().is_empty() || self.last_resource_mint_round().get() == 0 {
-            let mut first_round = self.blockchain().get_block_round();
+    /// Migrate the state of a single resource deployment, stored without the resource token, to the resource token it produces
+    /// The stake tokens are not accepted from the existing stakes, they are passed to the upgrade, replacing the stake token ticker
+    fn migrate_single_resource_state(&self) {
+        if self.legacy_stake_token_ticker().is_empty() {
+            return;
+        }
+        let stake_users: ManagedVec<ManagedAddress> = self.legacy_stakes_info().keys().collect();
+        require!(!self.legacy_resource_token_id().is_empty() || stake_users.is_empty(), ERR_LEGACY_RESOURCE_TOKEN_NOT_ISSUED);
+        self.legacy_stake_token_ticker().clear();
+        if self.legacy_resource_token_id().is_empty() {
+            self.legacy_mint_stake_threshold().clear();
+            self.legacy_mint_rounds_interval().clear();
+            return;
+        }
 
-            for (_, stakes) in self.stakes_info().iter() {
-                for stake in stakes.iter() {
-                    // Calculate a mint round based on the first stake found
-                    if stake.round < first_round {
-                        first_round = stake.round;
-                    }
-                }
-             }
-             first_round
-            } 
-        // If a previous mint round is set, return it
-        else { self.last_resource_mint_round().get() }
+        // Resource token and its mint state
+        let resource_token_id = self.legacy_resource_token_id().take();
+        self.resource_tokens().insert(resource_token_id.clone());
+        self.mint_stake_threshold(&resource_token_id).set(self.legacy_mint_stake_threshold().take());
+        self.mint_rounds_interval(&resource_token_id).set(self.legacy_mint_rounds_interval().take());
+        self.contract_has_local_mint_role(&resource_token_id).set(self.legacy_contract_has_local_mint_role().take());
+        self.last_resource_mint_round(&resource_token_id).set(self.legacy_last_resource_mint_round().take());
+        self.resources_to_mint(&resource_token_id).set(self.legacy_resources_to_mint().take());
+
+        // Users stakes, re-encoded with the resource token, and users resources
+        for user in stake_users.iter() {
+            // The legacy entry is removed first, as the new stakes are stored under the same key
+            let legacy_stakes = self.legacy_stakes_info().remove(&user).unwrap_or_default();
+            let mut user_stakes = ManagedVec::new();
+            for stake in legacy_stakes.iter() {
+                user_stakes.push(StakeInfo {
+                    token: stake.token.clone(),
+                    amount: stake.amount.clone(),
+                    round: stake.round,
+                    resource: resource_token_id.clone(),
+                });
+            }
+            self.stakes_info().insert(user.clone(), user_stakes);
+
+            self.user_minted_resources(&user, &resource_token_id).set(self.legacy_user_minted_resources(&user).take());
+            self.user_claimed_resources(&user, &resource_token_id).set(self.legacy_user_claimed_resources(&user).take());
+            self.user_has_unclaimed_resources(&user, &resource_token_id).set(self.legacy_user_has_unclaimed_resources(&user).take());
+        }
+    }
+
+    /// Require the token to be one of the contract resource tokens
+    fn require_resource_token(&self, resource_token_id: &TokenIdentifier) {
+        require!(self.resource_tokens().contains(resource_token_id), ERR_INVALID_RESOURCE_TOKEN);
     }
 }
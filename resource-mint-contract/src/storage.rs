@@ -6,20 +6,25 @@ use crate::data::*;
 /// Non required to be views, just for reading the contract state
 #[multiversx_sc::module]
 pub trait StorageModule {
-    /// Token ticker for stake tokens. E.g. WINTER
-    #[view(getStakeTokenTicker)]
-    #[storage_mapper("stakeTokenTicker")]
-    fn stake_token_ticker(&self) -> SingleValueMapper<ManagedBuffer>;
+    /// Accepted stake token IDs with their weight in basis points. E.g. WINTER-xxxxxx with 10000 for full weight
+    #[view(getAcceptedStakeTokens)]
+    #[storage_mapper("acceptedStakeTokens")]
+    fn accepted_stake_tokens(&self) -> MapMapper<TokenIdentifier, u64>;
+
+    /// Resource tokens minted by the contract
+    #[view(getResourceTokens)]
+    #[storage_mapper("resourceTokens")]
+    fn resource_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
 
-    /// Stake threshold for minting resources   
+    /// Stake threshold for minting one resource token
     #[view(getMintStakeThreshold)]
     #[storage_mapper("mintStakeThreshold")]
-    fn mint_stake_threshold(&self) -> SingleValueMapper<BigUint>;
+    fn mint_stake_threshold(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
-    /// Interval between mint rounds in number of rounds    
+    /// Interval between resource token mint rounds in number of rounds
     #[view(getMintRoundsInterval)]
     #[storage_mapper("mintRoundsInterval")]
-    fn mint_rounds_interval(&self) -> SingleValueMapper<u64>;
+    fn mint_rounds_interval(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
 
     /// Option to mint if user has claimed all previously minted resources
     #[view(getOptionMintIfClaimed)]
@@ -31,37 +36,201 @@ pub trait StorageModule {
     #[storage_mapper("stakesInfo")]
     fn stakes_info(&self) -> MapMapper<ManagedAddress, ManagedVec<StakeInfo<Self::Api>>>;
 
+    /// Number of rounds unstaked tokens are locked before they can be claimed
+    #[view(getUnstakeCooldownRounds)]
+    #[storage_mapper("unstakeCooldownRounds")]
+    fn unstake_cooldown_rounds(&self) -> SingleValueMapper<u64>;
+
+    /// User unstaked tokens waiting for the cooldown to pass
+    #[view(getPendingUnstakes)]
+    #[storage_mapper("pendingUnstakes")]
+    fn pending_unstakes(&self) -> MapMapper<ManagedAddress, ManagedVec<UnstakeInfo<Self::Api>>>;
+
     /// User minted resources
     #[view(getUserMintedResources)]
     #[storage_mapper("userMintedResources")]
-    fn user_minted_resources(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    fn user_minted_resources(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
     /// User claimed resources
     #[view(getUserClaimedResources)]
     #[storage_mapper("userClaimedResources")]
-    fn user_claimed_resources(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    fn user_claimed_resources(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// User fractional resource production carried over between mint intervals
+    /// Scaled by the resource production precision
+    #[view(getUserResourceProgress)]
+    #[storage_mapper("userResourceProgress")]
+    fn user_resource_progress(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// User resources lost to the storage decay
+    #[view(getUserDecayedResources)]
+    #[storage_mapper("userDecayedResources")]
+    fn user_decayed_resources(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// User storage capacity upgrades bought for the resource token
+    #[view(getUserStorageLevel)]
+    #[storage_mapper("userStorageLevel")]
+    fn user_storage_level(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// Base storage capacity of unclaimed resources for each user, 0 for unlimited storage
+    #[view(getBaseStorageCapacity)]
+    #[storage_mapper("baseStorageCapacity")]
+    fn base_storage_capacity(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Storage capacity added by each storage upgrade
+    #[view(getStorageUpgradeCapacity)]
+    #[storage_mapper("storageUpgradeCapacity")]
+    fn storage_upgrade_capacity(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Token and amount paid for each storage upgrade
+    #[view(getStorageUpgradePrice)]
+    #[storage_mapper("storageUpgradePrice")]
+    fn storage_upgrade_price(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<EsdtTokenPayment>;
+
+    /// Address receiving the storage upgrade payments in tokens the contract cannot burn
+    #[view(getStorageUpgradeTreasury)]
+    #[storage_mapper("storageUpgradeTreasury")]
+    fn storage_upgrade_treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Share of the unclaimed resources lost every mint interval, in basis points
+    #[view(getStorageDecayRate)]
+    #[storage_mapper("storageDecayRate")]
+    fn storage_decay_rate(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// Harvest seasons for the resource token, ordered by start round
+    #[view(getHarvestSeasons)]
+    #[storage_mapper("harvestSeasons")]
+    fn harvest_seasons(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<ManagedVec<HarvestSeason>>;
+
+    /// Keeper cut of the newly minted resources, in basis points
+    #[view(getKeeperRewardRate)]
+    #[storage_mapper("keeperRewardRate")]
+    fn keeper_reward_rate(&self) -> SingleValueMapper<u64>;
+
+    /// Keeper bounty token and amount paid for each rewarded mint
+    #[view(getKeeperBounty)]
+    #[storage_mapper("keeperBounty")]
+    fn keeper_bounty(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    /// Keeper bounty balance funded by the owner
+    #[view(getKeeperBountyBalance)]
+    #[storage_mapper("keeperBountyBalance")]
+    fn keeper_bounty_balance(&self) -> SingleValueMapper<BigUint>;
+
+    /// Minimum number of rounds between keeper rewards
+    #[view(getKeeperRewardCooldownRounds)]
+    #[storage_mapper("keeperRewardCooldownRounds")]
+    fn keeper_reward_cooldown_rounds(&self) -> SingleValueMapper<u64>;
+
+    /// Round of the last keeper reward
+    #[view(getLastKeeperRewardRound)]
+    #[storage_mapper("lastKeeperRewardRound")]
+    fn last_keeper_reward_round(&self) -> SingleValueMapper<u64>;
+
+    /// User auto-claim option
+    #[view(getUserAutoClaim)]
+    #[storage_mapper("userAutoClaim")]
+    fn user_auto_claim(&self, user: &ManagedAddress) -> SingleValueMapper<AutoClaimOption>;
+
+    /// Users opted in for auto-claim
+    #[view(getAutoClaimUsers)]
+    #[storage_mapper("autoClaimUsers")]
+    fn auto_claim_users(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Maximum number of auto-claim users processed by each autoClaimResources call
+    #[view(getAutoClaimBatchSize)]
+    #[storage_mapper("autoClaimBatchSize")]
+    fn auto_claim_batch_size(&self) -> SingleValueMapper<usize>;
+
+    /// Index of the last auto-claim user processed
+    #[storage_mapper("autoClaimCursor")]
+    fn auto_claim_cursor(&self) -> SingleValueMapper<usize>;
+
+    /// Auto-claim destination contract address, e.g., the Game Interface Contract
+    #[view(getAutoClaimDestination)]
+    #[storage_mapper("autoClaimDestination")]
+    fn auto_claim_destination(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Auto-claim destination endpoint called with the user address and the resources
+    #[view(getAutoClaimDestinationEndpoint)]
+    #[storage_mapper("autoClaimDestinationEndpoint")]
+    fn auto_claim_destination_endpoint(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Land NFT collection accepted for land stakes
+    #[view(getLandCollection)]
+    #[storage_mapper("landCollection")]
+    fn land_collection(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Land type boosting the resource token production. E.g. Forest for WOOD
+    #[view(getResourceLandType)]
+    #[storage_mapper("resourceLandType")]
+    fn resource_land_type(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<u8>;
+
+    /// Yield multiplier for each land tier, in basis points
+    #[view(getLandTierMultiplier)]
+    #[storage_mapper("landTierMultiplier")]
+    fn land_tier_multiplier(&self, tier: u8) -> SingleValueMapper<u64>;
+
+    /// Land staked by the user for the resource token
+    #[view(getUserLandStake)]
+    #[storage_mapper("userLandStake")]
+    fn user_land_stake(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier) -> SingleValueMapper<LandStake>;
 
     /// User has unclaimed resources
     #[view(getUserHasUnclaimedResources)]
     #[storage_mapper("userHasUnclaimedResources")]
-    fn user_has_unclaimed_resources(&self, address: &ManagedAddress<Self::Api>) -> SingleValueMapper<bool>;
-
-    /// Resource token ID
-    #[view(getResourceTokenId)]
-    #[storage_mapper("resourceTokenId")]
-    fn resource_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+    fn user_has_unclaimed_resources(&self, address: &ManagedAddress<Self::Api>, resource_token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
 
     /// Resource token has local mint role
     #[view(getContractHasLocalMintRole)]
     #[storage_mapper("contractHasLocalMintRole")]
-    fn contract_has_local_mint_role(&self) -> SingleValueMapper<bool>;
+    fn contract_has_local_mint_role(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<bool>;
 
     /// Last resource mint round
     #[view(getLastResourceMintRound)]
     #[storage_mapper("lastResourceMintRound")]
-    fn last_resource_mint_round(&self) -> SingleValueMapper<u64>;
+    fn last_resource_mint_round(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<u64>;
+
+    /// Decayed resources already minted or pending to be minted, deducted from the next mints
+    #[storage_mapper("decayedResources")]
+    fn decayed_resources(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
 
     /// Total resources to mint after last update
     #[storage_mapper("resourcesToMint")]
-    fn resources_to_mint(&self) -> SingleValueMapper<BigUint>;
+    fn resources_to_mint(&self, resource_token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    // Storage of a single resource deployment, migrated on upgrade to the resource token it produces
+
+    #[storage_mapper("stakeTokenTicker")]
+    fn legacy_stake_token_ticker(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    #[storage_mapper("resourceTokenId")]
+    fn legacy_resource_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("mintStakeThreshold")]
+    fn legacy_mint_stake_threshold(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("mintRoundsInterval")]
+    fn legacy_mint_rounds_interval(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("stakesInfo")]
+    fn legacy_stakes_info(&self) -> MapMapper<ManagedAddress, ManagedVec<LegacyStakeInfo<Self::Api>>>;
+
+    #[storage_mapper("userMintedResources")]
+    fn legacy_user_minted_resources(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userClaimedResources")]
+    fn legacy_user_claimed_resources(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userHasUnclaimedResources")]
+    fn legacy_user_has_unclaimed_resources(&self, user: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("contractHasLocalMintRole")]
+    fn legacy_contract_has_local_mint_role(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("lastResourceMintRound")]
+    fn legacy_last_resource_mint_round(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("resourcesToMint")]
+    fn legacy_resources_to_mint(&self) -> SingleValueMapper<BigUint>;
 }
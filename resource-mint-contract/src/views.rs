@@ -1,14 +1,235 @@
 use multiversx_sc::imports::*;
 
+use crate::constants::*;
+use crate::data::*;
+
 #[multiversx_sc::module]
 pub trait ViewsModule: crate::storage::StorageModule {
 
-    /// Returns number of user unclaimed resources
+    /// Returns number of user unclaimed resources for the resource token
     #[view(getUserUnclaimedResources)]
-    fn user_unclaimed_resources(&self, address: &ManagedAddress<Self::Api>) -> BigUint {
-        let user_minted = self.user_minted_resources(&address).get();
-        let user_claimed = self.user_claimed_resources(&address).get();
-        user_minted - user_claimed
+    fn user_unclaimed_resources(&self, address: &ManagedAddress<Self::Api>, resource_token_id: &TokenIdentifier) -> BigUint {
+        let user_minted = self.user_minted_resources(address, resource_token_id).get();
+        let user_claimed = self.user_claimed_resources(address, resource_token_id).get();
+        let user_decayed = self.user_decayed_resources(address, resource_token_id).get();
+        user_minted - user_claimed - user_decayed
+    }
+
+    /// Returns the active and upcoming harvest seasons for the resource token, ordered by start round
+    #[view(getUpcomingHarvestSeasons)]
+    fn get_upcoming_harvest_seasons(&self, resource_token_id: &TokenIdentifier) -> MultiValueEncoded<HarvestSeason> {
+        let current_round = self.blockchain().get_block_round();
+        let mut upcoming_seasons = MultiValueEncoded::new();
+        for season in self.harvest_seasons(resource_token_id).get().iter() {
+            if season.end_round > current_round {
+                upcoming_seasons.push(season.clone());
+            }
+        }
+        upcoming_seasons
+    }
+
+    /// Returns the user storage capacity for the resource token, 0 for unlimited storage
+    #[view(getUserStorageCapacity)]
+    fn user_storage_capacity(&self, address: &ManagedAddress<Self::Api>, resource_token_id: &TokenIdentifier) -> BigUint {
+        let base_capacity = self.base_storage_capacity(resource_token_id).get();
+        if base_capacity == 0 {
+            return base_capacity;
+        }
+        let user_level = self.user_storage_level(address, resource_token_id).get();
+        base_capacity + self.storage_upgrade_capacity(resource_token_id).get() * user_level
+    }
+
+    /// Returns the user unclaimed resources for all resource tokens
+    #[view(getUserAllUnclaimedResources)]
+    fn user_all_unclaimed_resources(&self, address: &ManagedAddress<Self::Api>) -> MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for resource_token_id in self.resource_tokens().iter() {
+            let user_unclaimed = self.user_unclaimed_resources(address, &resource_token_id);
+            result.push((resource_token_id, user_unclaimed).into());
+        }
+        result
+    }
+
+    /// Returns the round closing the first mint interval not yet minted for the resource token
+    /// A round already passed if closed intervals are pending to be minted, so resources can be minted at the current round
+    #[view(getNextMintRound)]
+    fn get_next_mint_round(&self, resource_token_id: &TokenIdentifier) -> u64 {
+        let mint_rounds_interval = self.mint_rounds_interval(resource_token_id).get();
+        require!(mint_rounds_interval > 0, ERR_MINT_ROUNDS_INTERVAL_ZERO);
+
+        self.get_last_mint_round(resource_token_id) + mint_rounds_interval
     }
-}
 
+    /// Returns the user resources projected for the mint intervals of the resource token not yet minted, based on the current state
+    /// The intervals already closed and pending to be minted are included with the current interval
+    #[view(getUserProjectedResources)]
+    fn get_user_projected_resources(&self, address: &ManagedAddress<Self::Api>, resource_token_id: &TokenIdentifier) -> BigUint {
+        let mint_stake_threshold = self.mint_stake_threshold(resource_token_id).get();
+        require!(mint_stake_threshold > 0, ERR_MINT_STAKE_THRESHOLD_ZERO);
+
+        let mint_rounds_interval = self.mint_rounds_interval(resource_token_id).get();
+        let current_interval_end_round = self.get_current_interval_end_round(resource_token_id);
+        let storage_decay_rate = self.storage_decay_rate(resource_token_id).get();
+
+        // Replay the mint calculation for each interval not yet minted, carrying the unclaimed resources and the fractional production
+        let mut user_unclaimed = self.user_unclaimed_resources(address, resource_token_id);
+        let mut user_progress = self.user_resource_progress(address, resource_token_id).get();
+        let mut projected_resources = BigUint::zero();
+        let mut end_mint_round = self.get_next_mint_round(resource_token_id);
+        while end_mint_round <= current_interval_end_round {
+            if storage_decay_rate > 0 {
+                user_unclaimed -= &user_unclaimed * storage_decay_rate / MAX_BASIS_POINTS;
+            }
+
+            let harvest_multiplier = self.get_harvest_multiplier(resource_token_id, end_mint_round);
+            if let Some((user_resources, progress)) = self.calculate_user_production(
+                address, resource_token_id, end_mint_round, &mint_stake_threshold, harvest_multiplier, &user_unclaimed, &user_progress
+            ) {
+                user_unclaimed += &user_resources;
+                projected_resources += user_resources;
+                user_progress = progress;
+            }
+            end_mint_round += mint_rounds_interval;
+        }
+        projected_resources
+    }
+
+    /// Returns the user stake counted in the current mint interval of the resource token, weighted by the stake token weights
+    #[view(getUserEffectiveStake)]
+    fn get_user_effective_stake(&self, address: &ManagedAddress<Self::Api>, resource_token_id: &TokenIdentifier) -> BigUint {
+        let current_interval_end_round = self.get_current_interval_end_round(resource_token_id);
+        self.get_user_weighted_stake(address, resource_token_id, current_interval_end_round) / MAX_BASIS_POINTS
+    }
+
+    /// Get the round closing the current mint interval of the resource token, after the intervals already closed
+    fn get_current_interval_end_round(&self, resource_token_id: &TokenIdentifier) -> u64 {
+        let mint_rounds_interval = self.mint_rounds_interval(resource_token_id).get();
+        require!(mint_rounds_interval > 0, ERR_MINT_ROUNDS_INTERVAL_ZERO);
+
+        let current_round = self.blockchain().get_block_round();
+        let last_mint_round = self.get_last_mint_round(resource_token_id);
+
+        let passed_intervals = (current_round - last_mint_round) / mint_rounds_interval;
+        last_mint_round + (passed_intervals + 1) * mint_rounds_interval
+    }
+
+    /// Get user stake for the resource counted in the mint interval ending at the round
+    /// The stake amounts are weighted by the stake token weight in basis points. Removed stake tokens are not counted
+    fn get_user_weighted_stake(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier, end_mint_round: u64) -> BigUint {
+        let mut total_user_stake_amount = BigUint::zero();
+        let user_stakes = self.stakes_info().get(user).unwrap_or_default();
+        for stake in user_stakes.iter() {
+            if stake.resource == *resource_token_id && stake.round < end_mint_round {
+                let stake_token_weight = self.accepted_stake_tokens().get(&stake.token).unwrap_or_default();
+                total_user_stake_amount += &stake.amount * stake_token_weight;
+            }
+        }
+        total_user_stake_amount
+    }
+
+    /// Calculate user resource production for the mint interval ending at the round
+    /// Returns the whole resources produced and the fractional production carried over, or None if the user doesn't produce in the interval
+    fn calculate_user_interval_production(
+        &self,
+        user: &ManagedAddress,
+        resource_token_id: &TokenIdentifier,
+        end_mint_round: u64,
+        mint_stake_threshold: &BigUint,
+        harvest_multiplier: u64,
+    ) -> Option<(BigUint, BigUint)> {
+        let user_unclaimed = self.user_unclaimed_resources(user, resource_token_id);
+        let user_progress = self.user_resource_progress(user, resource_token_id).get();
+        self.calculate_user_production(user, resource_token_id, end_mint_round, mint_stake_threshold, harvest_multiplier, &user_unclaimed, &user_progress)
+    }
+
+    /// Calculate user resource production for the mint interval ending at the round, from the given unclaimed resources and fractional production
+    #[allow(clippy::too_many_arguments)]
+    fn calculate_user_production(
+        &self,
+        user: &ManagedAddress,
+        resource_token_id: &TokenIdentifier,
+        end_mint_round: u64,
+        mint_stake_threshold: &BigUint,
+        harvest_multiplier: u64,
+        user_unclaimed: &BigUint,
+        user_progress: &BigUint,
+    ) -> Option<(BigUint, BigUint)> {
+        // Skip users that have unclaimed resources if option is set
+        if self.option_mint_if_claimed().get() && self.user_has_unclaimed_resources(user, resource_token_id).get() {
+            return None;
+        }
+
+        let total_user_stake_amount = self.get_user_weighted_stake(user, resource_token_id, end_mint_round);
+        if total_user_stake_amount == BigUint::zero() {
+            return None;
+        }
+
+        // Skip users with a full storage, production stops until resources are claimed
+        let user_storage_capacity = self.user_storage_capacity(user, resource_token_id);
+        if user_storage_capacity > 0 && *user_unclaimed >= user_storage_capacity {
+            return None;
+        }
+
+        // Calculate resource production based on stake amount, harvest and land multipliers with fixed-point precision,
+        // adding the fractional production carried over from the previous intervals
+        let land_multiplier = self.get_land_multiplier(user, resource_token_id, end_mint_round);
+        let precision = BigUint::from(RESOURCE_PRODUCTION_PRECISION);
+        let user_production = total_user_stake_amount * &precision * harvest_multiplier * land_multiplier
+            / (mint_stake_threshold * MAX_BASIS_POINTS * MAX_BASIS_POINTS * MAX_BASIS_POINTS)
+            + user_progress;
+
+        // Whole resources are minted and the remaining fraction is carried over to the next interval
+        let user_resources = &user_production / &precision;
+        let user_progress = user_production % &precision;
+
+        // Limit the resources to the available storage, any production over the capacity is lost
+        if user_storage_capacity > 0 && user_unclaimed + &user_resources > user_storage_capacity {
+            return Some((user_storage_capacity - user_unclaimed, BigUint::zero()));
+        }
+
+        Some((user_resources, user_progress))
+    }
+
+    /// Get last mint round for the resource
+    fn get_last_mint_round(&self, resource_token_id: &TokenIdentifier) -> u64 {
+        // If no previous mint round is set initially
+        if self.last_resource_mint_round(resource_token_id).is_empty() || self.last_resource_mint_round(resource_token_id).get() == 0 {
+            let mut first_round = self.blockchain().get_block_round();
+
+            for (_, stakes) in self.stakes_info().iter() {
+                for stake in stakes.iter() {
+                    // Calculate a mint round based on the first stake found for the resource
+                    if stake.resource == *resource_token_id && stake.round < first_round {
+                        first_round = stake.round;
+                    }
+                }
+             }
+             first_round
+            } 
+        // If a previous mint round is set, return it
+        else { self.last_resource_mint_round(resource_token_id).get() }
+    }
+
+    /// Get land multiplier in basis points for the user land counted in the mint interval ending at the round
+    /// The base multiplier is returned if no land is staked or the land tier multiplier is not set
+    fn get_land_multiplier(&self, user: &ManagedAddress, resource_token_id: &TokenIdentifier, end_mint_round: u64) -> u64 {
+        if self.user_land_stake(user, resource_token_id).is_empty() {
+            return MAX_BASIS_POINTS;
+        }
+        let land_stake = self.user_land_stake(user, resource_token_id).get();
+        if land_stake.round >= end_mint_round || self.land_tier_multiplier(land_stake.tier).is_empty() {
+            return MAX_BASIS_POINTS;
+        }
+        self.land_tier_multiplier(land_stake.tier).get()
+    }
+
+    /// Get harvest season multiplier in basis points for the round, the base multiplier if no season is active
+    fn get_harvest_multiplier(&self, resource_token_id: &TokenIdentifier, round: u64) -> u64 {
+        for season in self.harvest_seasons(resource_token_id).get().iter() {
+            if season.start_round <= round && round < season.end_round {
+                return season.multiplier;
+            }
+        }
+        MAX_BASIS_POINTS
+    }
+}
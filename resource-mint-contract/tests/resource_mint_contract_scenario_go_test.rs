@@ -8,3 +8,58 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/resource_mint_contract.scen.json");
 }
+
+#[test]
+fn unstake_tokens_go() {
+    world().run("scenarios/unstake_tokens.scen.json");
+}
+
+#[test]
+fn upgrade_single_resource_go() {
+    world().run("scenarios/upgrade_single_resource.scen.json");
+}
+
+#[test]
+fn upgrade_storage_capacity_go() {
+    world().run("scenarios/upgrade_storage_capacity.scen.json");
+}
+
+#[test]
+fn mint_resources_keeper_go() {
+    world().run("scenarios/mint_resources_keeper.scen.json");
+}
+
+#[test]
+fn auto_claim_go() {
+    world().run("scenarios/auto_claim.scen.json");
+}
+
+#[test]
+fn land_stake_go() {
+    world().run("scenarios/land_stake.scen.json");
+}
+
+#[test]
+fn unstake_same_round_go() {
+    world().run("scenarios/unstake_same_round.scen.json");
+}
+
+#[test]
+fn harvest_seasons_go() {
+    world().run("scenarios/harvest_seasons.scen.json");
+}
+
+#[test]
+fn storage_decay_go() {
+    world().run("scenarios/storage_decay.scen.json");
+}
+
+#[test]
+fn storage_upgrade_without_burn_role_go() {
+    world().run("scenarios/storage_upgrade_without_burn_role.scen.json");
+}
+
+#[test]
+fn mint_projection_go() {
+    world().run("scenarios/mint_projection.scen.json");
+}
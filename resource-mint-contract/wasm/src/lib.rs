@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           20
+// Endpoints:                           76
 // Async Callback:                       1
-// Total number of exported functions:  23
+// Promise callbacks:                    1
+// Total number of exported functions:  80
 
 #![no_std]
 
@@ -21,25 +22,82 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         stakeTokens => stake_tokens
+        stakeLand => stake_land
+        unstakeLand => unstake_land
+        unstakeTokens => unstake_tokens
+        claimUnstakedTokens => claim_unstaked_tokens
         mintResources => mint_resources
         claimResources => claim_resources
-        getStakeTokenTicker => stake_token_ticker
+        setAutoClaim => set_auto_claim
+        autoClaimResources => auto_claim_resources
+        upgradeStorageCapacity => upgrade_storage_capacity
+        getAcceptedStakeTokens => accepted_stake_tokens
+        getResourceTokens => resource_tokens
         getMintStakeThreshold => mint_stake_threshold
         getMintRoundsInterval => mint_rounds_interval
         getOptionMintIfClaimed => option_mint_if_claimed
         getStakeInfo => stakes_info
+        getUnstakeCooldownRounds => unstake_cooldown_rounds
+        getPendingUnstakes => pending_unstakes
         getUserMintedResources => user_minted_resources
         getUserClaimedResources => user_claimed_resources
+        getUserResourceProgress => user_resource_progress
+        getUserDecayedResources => user_decayed_resources
+        getUserStorageLevel => user_storage_level
+        getBaseStorageCapacity => base_storage_capacity
+        getStorageUpgradeCapacity => storage_upgrade_capacity
+        getStorageUpgradePrice => storage_upgrade_price
+        getStorageUpgradeTreasury => storage_upgrade_treasury
+        getStorageDecayRate => storage_decay_rate
+        getHarvestSeasons => harvest_seasons
+        getKeeperRewardRate => keeper_reward_rate
+        getKeeperBounty => keeper_bounty
+        getKeeperBountyBalance => keeper_bounty_balance
+        getKeeperRewardCooldownRounds => keeper_reward_cooldown_rounds
+        getLastKeeperRewardRound => last_keeper_reward_round
+        getUserAutoClaim => user_auto_claim
+        getAutoClaimUsers => auto_claim_users
+        getAutoClaimBatchSize => auto_claim_batch_size
+        getAutoClaimDestination => auto_claim_destination
+        getAutoClaimDestinationEndpoint => auto_claim_destination_endpoint
+        getLandCollection => land_collection
+        getResourceLandType => resource_land_type
+        getLandTierMultiplier => land_tier_multiplier
+        getUserLandStake => user_land_stake
         getUserHasUnclaimedResources => user_has_unclaimed_resources
-        getResourceTokenId => resource_token_id
         getContractHasLocalMintRole => contract_has_local_mint_role
         getLastResourceMintRound => last_resource_mint_round
+        addStakeToken => add_stake_token
+        removeStakeToken => remove_stake_token
         setMintRoundsInterval => set_mint_rounds_interval
         setStakeThreshold => set_stake_threshold
         setOptionMintIfClaimed => set_option_mint_if_claimed
+        setUnstakeCooldownRounds => set_unstake_cooldown_rounds
+        setBaseStorageCapacity => set_base_storage_capacity
+        setStorageUpgrade => set_storage_upgrade
+        setStorageUpgradeTreasury => set_storage_upgrade_treasury
+        setStorageDecayRate => set_storage_decay_rate
+        addHarvestSeason => add_harvest_season
+        removeHarvestSeason => remove_harvest_season
+        setKeeperRewardRate => set_keeper_reward_rate
+        setKeeperBounty => set_keeper_bounty
+        fundKeeperBounty => fund_keeper_bounty
+        setKeeperRewardCooldownRounds => set_keeper_reward_cooldown_rounds
+        setAutoClaimDestination => set_auto_claim_destination
+        setAutoClaimBatchSize => set_auto_claim_batch_size
+        setLandCollection => set_land_collection
+        setResourceLandType => set_resource_land_type
+        setLandTierMultiplier => set_land_tier_multiplier
         issueResourceToken => issue_resource_token
         setContractLocalMintRole => set_contract_local_mint_role
         getUserUnclaimedResources => user_unclaimed_resources
+        getUpcomingHarvestSeasons => get_upcoming_harvest_seasons
+        getUserStorageCapacity => user_storage_capacity
+        getUserAllUnclaimedResources => user_all_unclaimed_resources
+        getNextMintRound => get_next_mint_round
+        getUserProjectedResources => get_user_projected_resources
+        getUserEffectiveStake => get_user_effective_stake
+        auto_claim_destination_callback => auto_claim_destination_callback
     )
 }
 
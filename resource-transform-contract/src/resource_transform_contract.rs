@@ -2,6 +2,8 @@
 
 #[allow(unused_imports)]
 use multiversx_sc::imports::*;
+use multiversx_sc::derive::*;
+use multiversx_sc::proxy_imports::*;
 
 const ISSUE_FEE: u64 = 50_000_000_000_000_000; // 0.05 EGLD (0.05 * 10^18 decimals)
 pub const ORE_TOKEN_NAME: &str = "Ore";
@@ -11,6 +13,47 @@ pub const ORE_TOKEN_DECIMALS: usize = 0;
 pub const STONE_TOKEN_TICKER: &str = "STONE-";
 pub const STONE_TOKEN_DECIMALS: usize = 0;
 pub const STONE_AMMOUNT_FOR_ORE: u64 = 20;
+pub const MAX_BONDING_CURVE_STONE_PER_ORE: u64 = 1_000_000_000;
+
+pub const MAX_SMELTING_JOBS_DEFAULT: usize = 10;
+
+/// Bonding curve structure for the STONE needed per ORE
+/// STONE per ORE = base_stone_per_ore + stone_increase_per_step * (ORE minted / ore_per_step)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct OreBondingCurve {
+    pub base_stone_per_ore: u64,
+    pub stone_increase_per_step: u64,
+    pub ore_per_step: u64,
+    pub per_epoch: bool,
+}
+
+pub const RECIPE_TOKEN_DECIMALS: usize = 0;
+
+/// Smelting job structure with the ORE amount to be released to the receiver after the smelting duration
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct SmeltingJob<M: ManagedTypeApi> {
+    pub start_timestamp: u64,
+    pub ore_amount: BigUint<M>,
+    pub receiver_address: ManagedAddress<M>,
+}
+
+/// Smelting job structure before the receiver was stored, queued under the receiver
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct LegacySmeltingJob<M: ManagedTypeApi> {
+    pub start_timestamp: u64,
+    pub ore_amount: BigUint<M>,
+}
+
+/// Recipe structure with the input tokens burned and the output tokens minted for one transformation
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct Recipe<M: ManagedTypeApi> {
+    pub inputs: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub outputs: ManagedVec<M, EsdtTokenPayment<M>>,
+}
 
 #[multiversx_sc::contract]
 pub trait ResourceTransformContract {
@@ -59,9 +102,196 @@ pub trait ResourceTransformContract {
         }
     }
 
+    /// Issue and set all roles for a new recipe output token (e.g., PLANK, STEEL)
+    #[only_owner]
+    #[payable]
+    #[endpoint(issueRecipeToken)]
+    fn issue_recipe_token(&self, token_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        let payment = self.call_value().egld();
+        require!(*payment == ISSUE_FEE, "Send 0.05 EGLD for the issue cost.");
+
+        self.send()
+            .esdt_system_sc_tx()
+            .issue_and_set_all_roles(
+                payment.clone_value(),
+                token_name,
+                token_ticker,
+                EsdtTokenType::Fungible,
+                RECIPE_TOKEN_DECIMALS,
+            )
+            .with_callback(self.callbacks().issue_recipe_token_callback())
+            .async_call_and_exit();
+    }
+
+    /// Callback for the recipe token issue transaction to add the issued token to the recipe tokens
+    #[callback]
+    fn issue_recipe_token_callback(&self, #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.recipe_tokens().insert(token_id);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                let returned = self.call_value().egld_or_single_esdt();
+                if returned.token_identifier.is_egld() && returned.amount > 0 {
+                    self.tx().to(ToCaller).egld(returned.amount).transfer();
+                }
+            },
+        }
+    }
+
+    /// Register an existing token the contract can mint (e.g., ORE) as a recipe output token
+    /// The contract must have the local mint role for the token
+    #[only_owner]
+    #[endpoint(addRecipeToken)]
+    fn add_recipe_token(&self, token_id: TokenIdentifier) {
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token identifier.");
+        require!(self.recipe_tokens().insert(token_id.clone()), "Recipe token {} already added.", token_id);
+    }
+
+    /// Remove a recipe output token not used by any recipe
+    #[only_owner]
+    #[endpoint(removeRecipeToken)]
+    fn remove_recipe_token(&self, token_id: TokenIdentifier) {
+        for recipe in self.recipes().values() {
+            for output in recipe.outputs.iter() {
+                require!(output.token_identifier != token_id, "Recipe token {} is used by a recipe.", token_id);
+            }
+        }
+        require!(self.recipe_tokens().swap_remove(&token_id), "Recipe token {} not found.", token_id);
+    }
+
+    /// Add or replace a recipe
+    /// The output tokens must be recipe tokens, so the contract only mints tokens it was set up to mint
+    /// The contract must have the local burn role for the input tokens and the local mint role for the output tokens
+    #[only_owner]
+    #[endpoint(setRecipe)]
+    fn set_recipe(&self, recipe_id: u64, inputs: ManagedVec<EsdtTokenPayment>, outputs: ManagedVec<EsdtTokenPayment>) {
+        require!(!inputs.is_empty() && !outputs.is_empty(), "Recipe must have at least one input and one output.");
+
+        for (index, input) in inputs.iter().enumerate() {
+            require!(input.amount > 0, "Recipe amounts must be greater than 0.");
+            // Each input token can be listed only once, so payments match the inputs one to one
+            let token_id = &input.token_identifier;
+            for other_input in inputs.iter().skip(index + 1) {
+                require!(other_input.token_identifier != *token_id, "Recipe input {} is listed more than once.", token_id);
+            }
+        }
+        for output in outputs.iter() {
+            require!(output.amount > 0, "Recipe amounts must be greater than 0.");
+            let token_id = &output.token_identifier;
+            require!(self.recipe_tokens().contains(token_id), "Recipe output {} is not a recipe token.", token_id);
+        }
+
+        self.recipes().insert(recipe_id, Recipe { inputs, outputs });
+    }
+
+    /// Remove a recipe
+    #[only_owner]
+    #[endpoint(removeRecipe)]
+    fn remove_recipe(&self, recipe_id: u64) {
+        require!(self.recipes().remove(&recipe_id).is_some(), "Recipe {} not found.", recipe_id);
+    }
+
+    /// Set the time in seconds to smelt ore, 0 for instant ore creation
+    #[only_owner]
+    #[endpoint(setSmeltOreSeconds)]
+    fn set_smelt_ore_seconds(&self, smelt_ore_seconds: u64) {
+        self.smelt_ore_seconds().set(smelt_ore_seconds);
+    }
+
+    /// Enable the bonding curve mode where the STONE needed per ORE rises with the ORE minted
+    ///
+    /// # Arguments
+    /// * `base_stone_per_ore` - STONE needed per ORE when no ORE was minted
+    /// * `stone_increase_per_step` - STONE added to the rate for each step of ORE minted
+    /// * `ore_per_step` - ORE minted for each rate step
+    /// * `per_epoch` - If true, the ORE minted in the current epoch is used, otherwise the cumulative ORE minted
+    #[only_owner]
+    #[endpoint(setOreBondingCurve)]
+    fn set_ore_bonding_curve(&self, base_stone_per_ore: u64, stone_increase_per_step: u64, ore_per_step: u64, per_epoch: bool) {
+        require!(base_stone_per_ore > 0, "Base stone per ore must be greater than 0.");
+        require!(base_stone_per_ore <= MAX_BONDING_CURVE_STONE_PER_ORE, "Base stone per ore must be at most {}.", MAX_BONDING_CURVE_STONE_PER_ORE);
+        require!(stone_increase_per_step <= MAX_BONDING_CURVE_STONE_PER_ORE, "Stone increase per step must be at most {}.", MAX_BONDING_CURVE_STONE_PER_ORE);
+        require!(ore_per_step > 0, "Ore per step must be greater than 0.");
+
+        self.ore_bonding_curve().set(OreBondingCurve {
+            base_stone_per_ore,
+            stone_increase_per_step,
+            ore_per_step,
+            per_epoch,
+        });
+    }
+
+    /// Disable the bonding curve mode and go back to the fixed STONE per ORE rate
+    #[only_owner]
+    #[endpoint(disableOreBondingCurve)]
+    fn disable_ore_bonding_curve(&self) {
+        self.ore_bonding_curve().clear();
+    }
+
+    /// Set the maximum number of smelting jobs a user can have in the queue
+    #[only_owner]
+    #[endpoint(setMaxSmeltingJobs)]
+    fn set_max_smelting_jobs(&self, max_smelting_jobs: usize) {
+        require!(max_smelting_jobs > 0, "Max smelting jobs must be greater than 0.");
+        self.max_smelting_jobs().set(max_smelting_jobs);
+    }
+
     // Public endpoints
 
+    /// Transform the input tokens of a recipe into its output tokens
+    /// The payment must contain exactly the recipe inputs multiplied by the multiplier
+    ///
+    /// # Arguments
+    /// * `recipe_id` - Recipe identifier
+    /// * `multiplier` - Number of times the recipe is applied
+    /// * `receiver_address` - Receiver address optional, if not specified the caller address will be used
+    #[payable]
+    #[endpoint(transform)]
+    fn transform(&self, recipe_id: u64, multiplier: u64, receiver_address: OptionalValue<ManagedAddress>) {
+        require!(multiplier > 0, "Multiplier must be greater than 0.");
+        let recipe = match self.recipes().get(&recipe_id) {
+            Some(recipe) => recipe,
+            None => sc_panic!("Recipe {} not found.", recipe_id),
+        };
+
+        // Check the payments match the recipe inputs
+        let payments = self.call_value().all_esdt_transfers();
+        let inputs_count = recipe.inputs.len();
+        require!(payments.len() == inputs_count, "Send exactly the {} recipe input tokens.", inputs_count);
+        for input in recipe.inputs.iter() {
+            let input_token_id = &input.token_identifier;
+            let input_amount = &input.amount * multiplier;
+            let payment_found = payments.iter().any(|payment|
+                payment.token_identifier == *input_token_id && payment.token_nonce == 0 && payment.amount == input_amount);
+            require!(payment_found, "Recipe requires {} {}.", input_amount, input_token_id);
+        }
+
+        // Burn the inputs
+        for payment in payments.iter() {
+            self.send().esdt_local_burn(&payment.token_identifier, 0, &payment.amount);
+        }
+
+        // Mint the outputs
+        let mut output_payments = ManagedVec::new();
+        for output in recipe.outputs.iter() {
+            let output_amount = &output.amount * multiplier;
+            self.send().esdt_local_mint(&output.token_identifier, 0, &output_amount);
+            output_payments.push(EsdtTokenPayment::new(output.token_identifier.clone(), 0, output_amount));
+        }
+
+        // Determine the receiver address
+        let receiver_address = match receiver_address.into_option() {
+            Some(address) => address,
+            None => self.blockchain().get_caller(),
+        };
+
+        // Send the outputs to the receiver address
+        self.send().direct_multi(&receiver_address, &output_payments);
+    }
+
     /// Create ore by burning the stone tokens
+    /// If a smelting duration is set, the ore is queued and can be claimed with claimOre after the duration
     #[payable]
     #[endpoint(createOre)]
     fn create_ore(&self, receiver_address: OptionalValue<ManagedAddress>) {
@@ -72,23 +302,16 @@ pub trait ResourceTransformContract {
         // Check expected token
         self.require_expected_token(&token_id, STONE_TOKEN_TICKER);
 
-        // Get the amount of stone received
-        let stone_amount = payment_amount.clone() / 10u64.pow(STONE_TOKEN_DECIMALS as u32);
+        // Calculate the amount of ore, the stone consumed and the stone left over
+        let (ore_amount, stone_consumed, stone_leftover) = self.calculate_ore_quote(&payment_amount);
 
         // Check that the amount of stone is greater than or equal to the amount needed for one ore
-        require!(stone_amount.clone() >= STONE_AMMOUNT_FOR_ORE, "Stone amount must be equal or greater than {}.", STONE_AMMOUNT_FOR_ORE);
-        
-        // Calculate the amount of ore units by dividing the stone amount by the amount needed for one ore unit
-        let ore_units = (stone_amount / STONE_AMMOUNT_FOR_ORE).to_u64().unwrap_or_default();
-        
-        // Calculate the amount of ore to mint
-        let ore_amount = BigUint::from(ore_units)*BigUint::from(10u64).pow(ORE_TOKEN_DECIMALS as u32);
-        
-        // Mint the amount of ore
-        self.ore_token_id().mint(ore_amount.clone());
-        
-        // Burn the amount of stone
-        self.send().esdt_local_burn(&token_id, 0, &payment_amount);
+        let stone_per_ore = self.get_stone_per_ore(self.get_ore_minted_for_rate());
+        require!(ore_amount > 0, "Stone amount must be equal or greater than {}.", stone_per_ore);
+
+        // Record the ore minted for the bonding curve
+        let ore_units = (&ore_amount / &BigUint::from(10u64).pow(ORE_TOKEN_DECIMALS as u32)).to_u64().unwrap_or(u64::MAX);
+        self.record_ore_minted(ore_units);
         
         // Determine the receiver address
         let receiver_address = match receiver_address.into_option() {
@@ -96,13 +319,255 @@ pub trait ResourceTransformContract {
             None => self.blockchain().get_caller(),
         };
 
-        // Send the amount of ore to the receiver address
-        self.send().direct_esdt(&receiver_address, &self.ore_token_id().get_token_id(), 0, &ore_amount);
+        // Queue the ore if a smelting duration is set
+        // The job is queued under the caller, so only the caller's own queue can be filled
+        let smelt_ore_seconds = self.smelt_ore_seconds().get();
+        if smelt_ore_seconds > 0 {
+            let caller = self.blockchain().get_caller();
+            self.migrate_legacy_smelting_jobs(&caller);
+
+            let max_smelting_jobs = self.get_max_smelting_jobs();
+            let mut caller_ore_to_claim = self.ore_to_claim(&caller);
+            require!(caller_ore_to_claim.len() < max_smelting_jobs, "Smelting queue is full. Maximum {} jobs allowed.", max_smelting_jobs);
+
+            // Add the smelting job with the current timestamp and the receiver to the caller's list
+            caller_ore_to_claim.push(&SmeltingJob {
+                start_timestamp: self.blockchain().get_block_timestamp(),
+                ore_amount: ore_amount.clone(),
+                receiver_address: receiver_address.clone(),
+            });
+        }
+        else {
+            // Mint the amount of ore
+            self.ore_token_id().mint(ore_amount.clone());
+        }
+        
+        // Burn only the amount of stone converted
+        self.send().esdt_local_burn(&token_id, 0, &stone_consumed);
+
+        // Refund the stone that could not be converted to the caller
+        if stone_leftover > 0 {
+            self.send().direct_esdt(&self.blockchain().get_caller(), &token_id, 0, &stone_leftover);
+        }
+
+        // Send the amount of ore to the receiver address if not queued
+        if smelt_ore_seconds == 0 {
+            self.send().direct_esdt(&receiver_address, &self.ore_token_id().get_token_id(), 0, &ore_amount);
+        }
+    }
+
+    /// Claims the ore of the smelting jobs that are finished and sends it to the receiver of each job
+    ///
+    /// # Arguments
+    /// * `user_address` - Address that queued the smelting jobs optional, if not specified the caller address will be used
+    #[endpoint(claimOre)]
+    fn claim_ore(&self, user_address: OptionalValue<ManagedAddress>) {
+        self.ore_token_id().require_issued_or_set();
+
+        // Determine the user address if one is specified
+        let user = match user_address.into_option() {
+            Some(address) => address,
+            None => self.blockchain().get_caller(),
+        };
+        self.migrate_legacy_smelting_jobs(&user);
+
+        // Check if the user has any smelting jobs
+        let user_ore_to_claim = self.ore_to_claim(&user);
+        let jobs_pending_count = user_ore_to_claim.len();
+        require!(jobs_pending_count > 0, "No ore pending to be smelted.");
+
+        let smelt_ore_seconds = self.smelt_ore_seconds().get();
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        let mut ore_amount = BigUint::zero();
+        let mut ore_payments: ManagedVec<SmeltingJob<Self::Api>> = ManagedVec::new();
+        let mut still_smelting: ManagedVec<SmeltingJob<Self::Api>> = ManagedVec::new();
+
+        // Find finished smelting jobs and sum their ore by receiver
+        for job in user_ore_to_claim.iter() {
+            // Check if the smelting period has elapsed
+            if current_timestamp - job.start_timestamp < smelt_ore_seconds {
+                still_smelting.push(job);
+                continue;
+            }
+
+            ore_amount += &job.ore_amount;
+            match ore_payments.iter().position(|payment| payment.receiver_address == job.receiver_address) {
+                Some(index) => ore_payments.get_mut(index).ore_amount += &job.ore_amount,
+                None => ore_payments.push(job),
+            }
+        }
+
+        require!(ore_amount > 0, "No ore to claim. {} job(s) still in the smelting period.", jobs_pending_count);
+
+        // Update the user's smelting list
+        self.ore_to_claim(&user).clear();
+        for job in still_smelting.iter() {
+            self.ore_to_claim(&user).push(&job);
+        }
+
+        // Mint and send the ore to the receivers
+        self.ore_token_id().mint(ore_amount);
+        let ore_token_id = self.ore_token_id().get_token_id();
+        for payment in ore_payments.iter() {
+            self.send().direct_esdt(&payment.receiver_address, &ore_token_id, 0, &payment.ore_amount);
+        }
+    }
+
+    // Views
+
+    /// Returns the ORE output, the STONE consumed and the STONE left over for a given STONE amount
+    ///
+    /// # Arguments
+    /// * `stone_amount` - STONE amount to convert, including decimals
+    #[view(getOreQuote)]
+    fn get_ore_quote(&self, stone_amount: BigUint) -> MultiValue3<BigUint, BigUint, BigUint> {
+        self.calculate_ore_quote(&stone_amount).into()
+    }
+
+    /// Returns the current marginal rate, the STONE amount needed for the next ORE, including decimals
+    #[view(getOreMarginalRate)]
+    fn get_ore_marginal_rate(&self) -> BigUint {
+        self.get_stone_per_ore(self.get_ore_minted_for_rate()) * BigUint::from(10u64).pow(STONE_TOKEN_DECIMALS as u32)
     }
 
 
     // Private functions
 
+    /// Move the smelting jobs queued before the receiver was stored to the user's queue, with the user as receiver
+    fn migrate_legacy_smelting_jobs(&self, user: &ManagedAddress) {
+        let mut legacy_ore_to_claim = self.legacy_ore_to_claim(user);
+        if legacy_ore_to_claim.is_empty() {
+            return;
+        }
+
+        let mut user_ore_to_claim = self.ore_to_claim(user);
+        for job in legacy_ore_to_claim.iter() {
+            user_ore_to_claim.push(&SmeltingJob {
+                start_timestamp: job.start_timestamp,
+                ore_amount: job.ore_amount,
+                receiver_address: user.clone(),
+            });
+        }
+        legacy_ore_to_claim.clear();
+    }
+
+    /// Get the maximum number of smelting jobs per user
+    fn get_max_smelting_jobs(&self) -> usize {
+        if self.max_smelting_jobs().is_empty() { MAX_SMELTING_JOBS_DEFAULT }
+            else { self.max_smelting_jobs().get() }
+    }
+
+    /// Calculate the ORE amount, the STONE consumed and the STONE left over for a given STONE amount
+    /// With the bonding curve mode, the rate is recalculated at each step of ORE minted
+    fn calculate_ore_quote(&self, stone_amount: &BigUint) -> (BigUint, BigUint, BigUint) {
+        let stone_unit = BigUint::from(10u64).pow(STONE_TOKEN_DECIMALS as u32);
+        let stone_units = stone_amount / &stone_unit;
+        let ore_minted = self.get_ore_minted_for_rate();
+
+        let (ore_units, stone_units_consumed) = if self.ore_bonding_curve().is_empty() {
+            let stone_per_ore = self.get_stone_per_ore(ore_minted);
+            let ore_units = &stone_units / &stone_per_ore;
+            let stone_units_consumed = &ore_units * &stone_per_ore;
+            (ore_units, stone_units_consumed)
+        } else {
+            self.calculate_bonding_curve_quote(&self.ore_bonding_curve().get(), ore_minted, &stone_units)
+        };
+
+        let ore_amount = ore_units * BigUint::from(10u64).pow(ORE_TOKEN_DECIMALS as u32);
+        let stone_consumed = stone_units_consumed * stone_unit;
+        let stone_leftover = stone_amount - &stone_consumed;
+
+        (ore_amount, stone_consumed, stone_leftover)
+    }
+
+    /// Calculate the ORE units and the STONE units consumed on the bonding curve, without iterating over the rate steps
+    /// The ORE left in the current step is bought first, then the full steps whose cost is an arithmetic series, then the rest of the last step
+    fn calculate_bonding_curve_quote(&self, curve: &OreBondingCurve, ore_minted: u64, stone_units: &BigUint) -> (BigUint, BigUint) {
+        let ore_per_step = BigUint::from(curve.ore_per_step);
+        let stone_increase = BigUint::from(curve.stone_increase_per_step);
+        let stone_per_ore = self.get_stone_per_ore(ore_minted);
+
+        // Buy up to the next rate step at the current rate
+        let units_to_next_step = BigUint::from(curve.ore_per_step - ore_minted % curve.ore_per_step);
+        let affordable_units = stone_units / &stone_per_ore;
+        if affordable_units < units_to_next_step || stone_increase == 0 {
+            let stone_consumed = &affordable_units * &stone_per_ore;
+            return (affordable_units, stone_consumed);
+        }
+        let mut stone_consumed = &units_to_next_step * &stone_per_ore;
+        let stone_left = stone_units - &stone_consumed;
+
+        // Largest number of full steps n with increase * n^2 + (2 * next_rate - increase) * n <= 2 * stone_left / ore_per_step
+        let next_stone_per_ore = stone_per_ore + &stone_increase;
+        let linear_term = &next_stone_per_ore * 2u64 - &stone_increase;
+        let discriminant = &linear_term * &linear_term + &stone_increase * &(&stone_left / &ore_per_step) * 8u64;
+        let mut full_steps = (discriminant.sqrt() - &linear_term) / (&stone_increase * 2u64);
+
+        // Correct the integer square root rounding
+        while self.get_bonding_curve_steps_cost(&ore_per_step, &next_stone_per_ore, &stone_increase, &(&full_steps + 1u64)) <= stone_left {
+            full_steps += 1u64;
+        }
+        while full_steps > 0 && self.get_bonding_curve_steps_cost(&ore_per_step, &next_stone_per_ore, &stone_increase, &full_steps) > stone_left {
+            full_steps -= 1u64;
+        }
+        let full_steps_cost = self.get_bonding_curve_steps_cost(&ore_per_step, &next_stone_per_ore, &stone_increase, &full_steps);
+        stone_consumed += &full_steps_cost;
+
+        // Buy the rest of the last step, less than one step by the choice of full steps
+        let last_stone_per_ore = next_stone_per_ore + &stone_increase * &full_steps;
+        let last_units = (stone_left - full_steps_cost) / &last_stone_per_ore;
+        stone_consumed += &last_units * &last_stone_per_ore;
+
+        (units_to_next_step + full_steps * ore_per_step + last_units, stone_consumed)
+    }
+
+    /// Get the STONE units needed for the given number of full rate steps, starting at the given rate
+    /// ore_per_step * (steps * first_rate + increase * steps * (steps - 1) / 2)
+    fn get_bonding_curve_steps_cost(&self, ore_per_step: &BigUint, first_stone_per_ore: &BigUint, stone_increase: &BigUint, steps: &BigUint) -> BigUint {
+        if *steps == 0 {
+            return BigUint::zero();
+        }
+
+        let increase_sum = stone_increase * steps * (steps - 1u64) / 2u64;
+        ore_per_step * &(steps * first_stone_per_ore + increase_sum)
+    }
+
+    /// Get the STONE needed per ORE for the given amount of ORE minted
+    fn get_stone_per_ore(&self, ore_minted: u64) -> BigUint {
+        if self.ore_bonding_curve().is_empty() {
+            return BigUint::from(STONE_AMMOUNT_FOR_ORE);
+        }
+
+        let curve = self.ore_bonding_curve().get();
+        BigUint::from(curve.base_stone_per_ore) + BigUint::from(curve.stone_increase_per_step) * (ore_minted / curve.ore_per_step)
+    }
+
+    /// Get the ORE minted used for the bonding curve rate, cumulative or in the current epoch
+    fn get_ore_minted_for_rate(&self) -> u64 {
+        if self.ore_bonding_curve().is_empty() || !self.ore_bonding_curve().get().per_epoch {
+            return self.total_ore_minted().get();
+        }
+
+        if self.ore_minted_epoch().get() == self.blockchain().get_block_epoch() {
+            self.epoch_ore_minted().get()
+        } else {
+            0
+        }
+    }
+
+    /// Record the ORE minted, cumulative and in the current epoch
+    fn record_ore_minted(&self, ore_units: u64) {
+        self.total_ore_minted().update(|total| *total = total.saturating_add(ore_units));
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        if self.ore_minted_epoch().get() != current_epoch {
+            self.ore_minted_epoch().set(current_epoch);
+            self.epoch_ore_minted().set(ore_units);
+        } else {
+            self.epoch_ore_minted().update(|minted| *minted = minted.saturating_add(ore_units));
+        }
+    }
+
     /// Check if a token is a required token
     fn is_required_token(&self, check_token_id: &TokenIdentifier, required_token_ticker: &str) -> bool {
         check_token_id.as_managed_buffer().copy_slice(0, required_token_ticker.len()).unwrap_or_default()
@@ -121,4 +586,52 @@ pub trait ResourceTransformContract {
     #[view(getOreTokenId)]
     #[storage_mapper("oreTokenId")]
     fn ore_token_id(&self) -> FungibleTokenMapper;
+
+    /// Recipes by recipe identifier
+    #[view(getRecipes)]
+    #[storage_mapper("recipes")]
+    fn recipes(&self) -> MapMapper<u64, Recipe<Self::Api>>;
+
+    /// Recipe output tokens issued by the contract
+    #[view(getRecipeTokens)]
+    #[storage_mapper("recipeTokens")]
+    fn recipe_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Bonding curve for the STONE needed per ORE, empty for the fixed rate
+    #[view(getOreBondingCurve)]
+    #[storage_mapper("oreBondingCurve")]
+    fn ore_bonding_curve(&self) -> SingleValueMapper<OreBondingCurve>;
+
+    /// Cumulative ORE units minted by createOre
+    #[view(getTotalOreMinted)]
+    #[storage_mapper("totalOreMinted")]
+    fn total_ore_minted(&self) -> SingleValueMapper<u64>;
+
+    /// ORE units minted by createOre in the epoch stored in oreMintedEpoch
+    #[view(getEpochOreMinted)]
+    #[storage_mapper("epochOreMinted")]
+    fn epoch_ore_minted(&self) -> SingleValueMapper<u64>;
+
+    /// Epoch of the ORE units minted in epochOreMinted
+    #[storage_mapper("oreMintedEpoch")]
+    fn ore_minted_epoch(&self) -> SingleValueMapper<u64>;
+
+    /// Time in seconds to smelt ore, 0 for instant ore creation
+    #[view(getSmeltOreSeconds)]
+    #[storage_mapper("smeltOreSeconds")]
+    fn smelt_ore_seconds(&self) -> SingleValueMapper<u64>;
+
+    /// Maximum number of smelting jobs per user
+    #[view(getMaxSmeltingJobs)]
+    #[storage_mapper("maxSmeltingJobs")]
+    fn max_smelting_jobs(&self) -> SingleValueMapper<usize>;
+
+    /// Smelting jobs queued by each user, pending to be claimed
+    #[view(getOreToClaim)]
+    #[storage_mapper("smeltingJobs")]
+    fn ore_to_claim(&self, user: &ManagedAddress) -> VecMapper<SmeltingJob<Self::Api>>;
+
+    /// Smelting jobs queued under the receiver before the receiver was stored in the job
+    #[storage_mapper("oreToClaim")]
+    fn legacy_ore_to_claim(&self, user: &ManagedAddress) -> VecMapper<LegacySmeltingJob<Self::Api>>;
 }
@@ -8,3 +8,28 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/resource_transform_contract.scen.json");
 }
+
+#[test]
+fn recipe_outputs_go() {
+    world().run("scenarios/recipe_outputs.scen.json");
+}
+
+#[test]
+fn create_ore_refund_go() {
+    world().run("scenarios/create_ore_refund.scen.json");
+}
+
+#[test]
+fn ore_bonding_curve_go() {
+    world().run("scenarios/ore_bonding_curve.scen.json");
+}
+
+#[test]
+fn smelting_queue_go() {
+    world().run("scenarios/smelting_queue.scen.json");
+}
+
+#[test]
+fn ore_quote_go() {
+    world().run("scenarios/ore_quote.scen.json");
+}
@@ -12,3 +12,28 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/resource_transform_contract.scen.json");
 }
+
+#[test]
+fn recipe_outputs_rs() {
+    world().run("scenarios/recipe_outputs.scen.json");
+}
+
+#[test]
+fn create_ore_refund_rs() {
+    world().run("scenarios/create_ore_refund.scen.json");
+}
+
+#[test]
+fn ore_bonding_curve_rs() {
+    world().run("scenarios/ore_bonding_curve.scen.json");
+}
+
+#[test]
+fn smelting_queue_rs() {
+    world().run("scenarios/smelting_queue.scen.json");
+}
+
+#[test]
+fn ore_quote_rs() {
+    world().run("scenarios/ore_quote.scen.json");
+}
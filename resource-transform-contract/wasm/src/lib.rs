@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            3
+// Endpoints:                           24
 // Async Callback:                       1
-// Total number of exported functions:   6
+// Total number of exported functions:  27
 
 #![no_std]
 
@@ -21,8 +21,29 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         issueAndSetRolesOreToken => issue_and_set_roles_ore_token
+        issueRecipeToken => issue_recipe_token
+        addRecipeToken => add_recipe_token
+        removeRecipeToken => remove_recipe_token
+        setRecipe => set_recipe
+        removeRecipe => remove_recipe
+        setSmeltOreSeconds => set_smelt_ore_seconds
+        setOreBondingCurve => set_ore_bonding_curve
+        disableOreBondingCurve => disable_ore_bonding_curve
+        setMaxSmeltingJobs => set_max_smelting_jobs
+        transform => transform
         createOre => create_ore
+        claimOre => claim_ore
+        getOreQuote => get_ore_quote
+        getOreMarginalRate => get_ore_marginal_rate
         getOreTokenId => ore_token_id
+        getRecipes => recipes
+        getRecipeTokens => recipe_tokens
+        getOreBondingCurve => ore_bonding_curve
+        getTotalOreMinted => total_ore_minted
+        getEpochOreMinted => epoch_ore_minted
+        getSmeltOreSeconds => smelt_ore_seconds
+        getMaxSmeltingJobs => max_smelting_jobs
+        getOreToClaim => ore_to_claim
     )
 }
 
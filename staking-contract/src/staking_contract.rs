@@ -8,7 +8,7 @@ use multiversx_sc::proxy_imports::*;
 const ISSUE_FEE: u64 = 50_000_000_000_000_000; // 0.05 EGLD (0.05 * 10^18 decimals)
 
 const STAKE_UNLOCK_EPOCHS: u64 = 5; // 5 epochs
-const STAKE_TOKENID_PREFIX: &str = "WINTER-";
+const MAX_BASIS_POINTS: u64 = 10_000; // 100% stake token weight
 
 const REWARD_TOKEN_NAME: &str = "SnowMan";
 const REWARD_TOKEN_TICKER: &str = "SNOW";
@@ -16,7 +16,7 @@ const REWARD_TOKEN_DECIMALS: usize = 8;
 
 /// Stake info structure for each token and stake
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct StakeInfo<M: ManagedTypeApi> {
     pub token_id: TokenIdentifier<M>,
     pub amount: BigUint<M>,
@@ -36,8 +36,19 @@ pub trait StakingContract {
     #[init]
     fn init(&self) {}
 
+    /// Accept the given stake tokens with their weights when upgrading, e.g., the legitimate stake tokens
+    /// of the stakes made before the stake token weights, so those stakes keep earning rewards
+    /// The stakes of any other token don't earn rewards and are listed by the `getInactiveStakes` view
+    ///
+    /// # Arguments
+    /// * `stake_tokens` - Stake token IDs with their weight in basis points
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, stake_tokens: MultiValueEncoded<MultiValue2<TokenIdentifier, u64>>) {
+        for stake_token in stake_tokens {
+            let (token_id, weight) = stake_token.into_tuple();
+            self.insert_stake_token(token_id, weight);
+        }
+    }
     
 
     // Admin endpoints
@@ -151,6 +162,30 @@ pub trait StakingContract {
         }
     }
 
+    /// Add an accepted stake token by its full token ID, or update its weight if already added
+    /// The weight is set in basis points (e.g., 10000 for full weight, 5000 for half weight)
+    #[only_owner]
+    #[endpoint(addStakeToken)]
+    fn add_stake_token(&self, token_id: TokenIdentifier, weight: u64) {
+        self.insert_stake_token(token_id, weight);
+    }
+
+    /// Insert an accepted stake token with its weight in basis points
+    fn insert_stake_token(&self, token_id: TokenIdentifier, weight: u64) {
+        require!(token_id.is_valid_esdt_identifier(), "Invalid stake token ID.");
+        require!(weight > 0, "Stake token weight must be greater than 0.");
+
+        self.accepted_stake_tokens().insert(token_id, weight);
+    }
+
+    /// Remove an accepted stake token
+    /// Existing stakes of the token no longer earn rewards, listed by the `getInactiveStakes` view
+    #[only_owner]
+    #[endpoint(removeStakeToken)]
+    fn remove_stake_token(&self, token_id: TokenIdentifier) {
+        require!(self.accepted_stake_tokens().remove(&token_id).is_some(), "Stake token not found.");
+    }
+
 
     // Public endpoints
 
@@ -165,7 +200,7 @@ pub trait StakingContract {
         for payment in payments.iter() {
             let token_id = &payment.token_identifier;
 
-            self.require_expected_token(token_id, STAKE_TOKENID_PREFIX);
+            require!(self.accepted_stake_tokens().contains_key(token_id), "Invalid token {}. Not an accepted stake token.", token_id);
         }
 
         let caller = self.blockchain().get_caller();
@@ -217,6 +252,12 @@ pub trait StakingContract {
             let mut address_total_reward = BigUint::zero();
             
             for stake in stakes.iter() {
+                // Stakes of removed stake tokens don't earn rewards
+                let stake_token_weight = self.accepted_stake_tokens().get(&stake.token_id).unwrap_or_default();
+                if stake_token_weight == 0 {
+                    continue;
+                }
+
                 let stake_epoch = stake.unlock_epoch - STAKE_UNLOCK_EPOCHS;
                 
                 // If the last reward epoch is before or the same as the stake epoch, reward is from stake epoch to current epoch
@@ -228,8 +269,8 @@ pub trait StakingContract {
                 
                 
                 if reward_epochs > 0u64 {
-                    // Calculate reward for this stake: 1% of stake amount per reward epoch
-                    let stake_reward = &stake.amount / 100u32 * reward_epochs;
+                    // Calculate reward for this stake: 1% of stake amount per reward epoch, weighted by the stake token weight
+                    let stake_reward = &stake.amount * stake_token_weight / MAX_BASIS_POINTS / 100u32 * reward_epochs;
                     if stake_reward > 0u64 {
                         address_total_reward += stake_reward;
                     }
@@ -275,18 +316,6 @@ pub trait StakingContract {
     
     // Private functions
 
-    /// Check if a token is a required token
-    fn is_required_token(&self, check_token_id: &TokenIdentifier, required_token_ticker: &str) -> bool {
-        check_token_id.as_managed_buffer().copy_slice(0, required_token_ticker.len()).unwrap_or_default()
-         == ManagedBuffer::from(required_token_ticker.as_bytes())
-    }
-
-    /// Check if a token is a required token and terminates if not
-    fn require_expected_token(&self, check_token_id: &TokenIdentifier, required_token_ticker: &str) {
-        let expected_token = ManagedBuffer::from(required_token_ticker.as_bytes());
-        require!(self.is_required_token(check_token_id, required_token_ticker), "Invalid token {}. Expected {}.", check_token_id, expected_token);
-    }
-
     /// Returns the last epoch in which rewards were distributed
     /// If no rewards have been distributed, returns the epoch of the first stake
     /// If there are no stakes, returns the current epoch
@@ -309,6 +338,18 @@ pub trait StakingContract {
         last_reward_epoch
     }
 
+    /// Returns the user stakes of removed stake tokens, which don't earn rewards
+    #[view(getInactiveStakes)]
+    fn get_inactive_stakes(&self, address: &ManagedAddress) -> MultiValueEncoded<StakeInfo<Self::Api>> {
+        let mut inactive_stakes = MultiValueEncoded::new();
+        for stake in self.stake_info().get(address).unwrap_or_default().iter() {
+            if !self.accepted_stake_tokens().contains_key(&stake.token_id) {
+                inactive_stakes.push(stake.clone());
+            }
+        }
+        inactive_stakes
+    }
+
     /// Gets the reward address for a user, returns user address if not set
     #[view(getRewardAddress)]
     fn get_reward_address(&self, address: &ManagedAddress) -> ManagedAddress {
@@ -329,6 +370,11 @@ pub trait StakingContract {
     #[storage_mapper("stake_info")]
     fn stake_info(&self) -> MapMapper<ManagedAddress, ManagedVec<StakeInfo<Self::Api>>>;
     
+    /// Stores the accepted stake token IDs with their weight in basis points
+    #[view(getAcceptedStakeTokens)]
+    #[storage_mapper("accepted_stake_tokens")]
+    fn accepted_stake_tokens(&self) -> MapMapper<TokenIdentifier, u64>;
+
     /// Stores the last reward epoch
     #[view(getLastRewardEpoch)]
     #[storage_mapper("last_reward_epoch")]
@@ -8,3 +8,8 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/staking_contract.scen.json");
 }
+
+#[test]
+fn upgrade_stake_tokens_go() {
+    world().run("scenarios/upgrade_stake_tokens.scen.json");
+}
@@ -12,3 +12,8 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/staking_contract.scen.json");
 }
+
+#[test]
+fn upgrade_stake_tokens_rs() {
+    world().run("scenarios/upgrade_stake_tokens.scen.json");
+}
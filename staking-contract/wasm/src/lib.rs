@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            9
+// Endpoints:                           13
 // Async Callback:                       1
-// Total number of exported functions:  12
+// Total number of exported functions:  16
 
 #![no_std]
 
@@ -22,11 +22,15 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         issueRewardToken => issue_reward_token
         setRewardTokenLocalMintRole => set_reward_token_local_mint_role
+        addStakeToken => add_stake_token
+        removeStakeToken => remove_stake_token
         stakeTokenWinter => stake_token_winter
         distributeRewards => distribute_rewards
         setRewardAddress => set_reward_address
+        getInactiveStakes => get_inactive_stakes
         getRewardAddress => get_reward_address
         getStakeInfo => stake_info
+        getAcceptedStakeTokens => accepted_stake_tokens
         getLastRewardEpoch => last_reward_epoch
         getRewardTokenId => reward_token_id
     )
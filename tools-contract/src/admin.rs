@@ -5,7 +5,8 @@ use game_common_module::constants::*;
 
 #[multiversx_sc::module]
 pub trait AdminModule:
-    crate::storage::StorageModule {
+    crate::storage::StorageModule +
+    game_common_module::GameCommonModule {
 
     #[init]
     fn init(&self) {}
@@ -68,4 +69,29 @@ pub trait AdminModule:
         self.mint_sword_seconds().set(mint_sword_seconds);
     }
 
+    /// Set the share of the tool ORE and GOLD cost returned when salvaging a tool, in basis points
+    #[only_owner]
+    #[endpoint(setSalvageShare)]
+    fn set_salvage_share(&self, salvage_share: u64) {
+        require!(salvage_share <= MAX_SALVAGE_SHARE, "Salvage share must be at most {}.", MAX_SALVAGE_SHARE);
+        self.salvage_share().set(salvage_share);
+    }
+
+    /// Fund the salvage reserve with ORE or GOLD tokens used to pay the salvaged tools
+    #[only_owner]
+    #[payable]
+    #[endpoint(fundSalvageReserve)]
+    fn fund_salvage_reserve(&self) {
+        let payments = self.call_value().all_esdt_transfers();
+        require!(!payments.is_empty(), "Send ORE or GOLD tokens to fund the salvage reserve.");
+
+        for payment in payments.iter() {
+            let token_id = &payment.token_identifier;
+            require!(self.is_required_token(token_id, &ManagedBuffer::from(ORE_TICKER))
+                || self.is_required_token(token_id, &ManagedBuffer::from(GOLD_TICKER)), "Only ORE and GOLD tokens can fund the salvage reserve.");
+
+            self.add_to_reserve(self.salvage_reserve(), &payment);
+        }
+    }
+
 }
\ No newline at end of file
@@ -13,7 +13,9 @@ use game_common_module::constants::*;
 pub trait ToolsContract: 
     storage::StorageModule +
     admin::AdminModule +
-    game_common_module::GameCommonModule 
+    game_common_module::GameCommonModule +
+    game_common_module::pending_mint::PendingMintModule +
+    game_common_module::nft_attributes::NftAttributesModule
 {
 
     // Shield fuctionality
@@ -37,15 +39,16 @@ pub trait ToolsContract:
             };
 
         // Register the NFT mint to the receiver address
+        self.migrate_legacy_tools_to_mint(Tool::new_shield().tool_type, &user);
         let mut user_shields_to_mint = self.shields_to_mint().get(&user).unwrap_or_default();
-        // Record the current mint start timestamp
-        let mint_start_timestamp = self.blockchain().get_block_timestamp();
-        // Add the mint start timestamp to the mint user's list
-        user_shields_to_mint.push(mint_start_timestamp);
+        // Record the current mint start timestamp and hold the ore sent in escrow until claim
+        let mut payments = ManagedVec::new();
+        payments.push(EsdtTokenPayment::new(token_id.clone(), 0, payment_amount.clone()));
+        user_shields_to_mint.push(PendingMint {
+            start_timestamp: self.blockchain().get_block_timestamp(),
+            payments,
+        });
         self.shields_to_mint().insert(user.clone(), user_shields_to_mint);
-        
-        // Burn the ore sent
-        self.send().esdt_local_burn(&token_id, 0, &payment_amount);
 
     }
 
@@ -60,6 +63,7 @@ pub trait ToolsContract:
         };
 
         // Check if the user has any NFTs to mint
+        self.migrate_legacy_tools_to_mint(Tool::new_shield().tool_type, &user);
         let user_shields = self.shields_to_mint().get(&user).unwrap_or_default();
         let shields_pending_count = user_shields.len();
 
@@ -68,19 +72,22 @@ pub trait ToolsContract:
 
         let mut shields_minted = 0;
 
-        let mut still_minting : ManagedVec<u64> = ManagedVec::new();
+        let mut still_minting : ManagedVec<PendingMint<Self::Api>> = ManagedVec::new();
 
         let mint_shield_seconds = if self.mint_shield_seconds().is_empty() { MINT_SHIELD_SECONDS_DEFAULT }
             else { self.mint_shield_seconds().get() };
 
         // Find mintable shields
-        for timestamp in user_shields.iter() {
+        for pending_mint in user_shields.iter() {
         
             // Check if the minting period is over
-            if self.blockchain().get_block_timestamp() - timestamp <  mint_shield_seconds {
-                still_minting.push(timestamp);
+            if self.blockchain().get_block_timestamp() - pending_mint.start_timestamp <  mint_shield_seconds {
+                still_minting.push(pending_mint.clone());
                 continue;
             }
+
+            // Burn the escrowed payment
+            self.burn_pending_mint_payments(&pending_mint);
             
             // Mint the NFT
             let nft_nonce = self.create_shield_nft();
@@ -134,10 +141,10 @@ pub trait ToolsContract:
         let royalties = BigUint::from(SHIELD_NFT_ROYALTIES);
 
         // Get the attributes
-        let attributes = self.get_nft_attributes(&shield);
+        let attributes = self.get_tool_nft_attributes(&shield);
 
         // Get the URIs
-        let uris = self.get_nft_asset_uris(&shield);
+        let uris = self.get_tool_nft_asset_uris(&shield);
 
         // Get the attributes hash
         let attributes_sha256 = self.crypto().sha256(&attributes);
@@ -190,17 +197,14 @@ pub trait ToolsContract:
             };
 
         // Register the NFT mint to the receiver address
+        self.migrate_legacy_tools_to_mint(Tool::new_sword().tool_type, &user);
         let mut user_swords_to_mint = self.swords_to_mint().get(&user).unwrap_or_default();
-        // Record the current mint start timestamp
-        let mint_start_timestamp = self.blockchain().get_block_timestamp();
-        // Add the mint start timestamp to the mint user's list
-        user_swords_to_mint.push(mint_start_timestamp);
+        // Record the current mint start timestamp and hold the gold and ore sent in escrow until claim
+        user_swords_to_mint.push(PendingMint {
+            start_timestamp: self.blockchain().get_block_timestamp(),
+            payments: payments.clone_value(),
+        });
         self.swords_to_mint().insert(user.clone(), user_swords_to_mint);
-        
-        // Burn the gold and ore sent
-        for payment in payments.iter() {
-            self.send().esdt_local_burn(&payment.token_identifier, 0, &payment.amount);
-        }
     }
 
     /// Claims a Sword NFT if the minting period is over
@@ -214,6 +218,7 @@ pub trait ToolsContract:
         };
 
         // Check if the user has any NFTs to mint
+        self.migrate_legacy_tools_to_mint(Tool::new_sword().tool_type, &user);
         let user_swords = self.swords_to_mint().get(&user).unwrap_or_default();
         let swords_pending_count = user_swords.len();
 
@@ -222,19 +227,22 @@ pub trait ToolsContract:
 
         let mut swords_minted = 0;
 
-        let mut still_minting : ManagedVec<u64> = ManagedVec::new();
+        let mut still_minting : ManagedVec<PendingMint<Self::Api>> = ManagedVec::new();
 
         let mint_sword_seconds = if self.mint_sword_seconds().is_empty() { MINT_SWORD_SECONDS_DEFAULT } 
             else { self.mint_sword_seconds().get() };
 
         // Find mintable swords
-        for timestamp in user_swords.iter() {
+        for pending_mint in user_swords.iter() {
         
             // Check if the minting period is over
-            if self.blockchain().get_block_timestamp() - timestamp <  mint_sword_seconds {
-                still_minting.push(timestamp);
+            if self.blockchain().get_block_timestamp() - pending_mint.start_timestamp <  mint_sword_seconds {
+                still_minting.push(pending_mint.clone());
                 continue;
             }
+
+            // Burn the escrowed payment
+            self.burn_pending_mint_payments(&pending_mint);
             
             // Mint the NFT
             let nft_nonce = self.create_sword_nft();
@@ -288,10 +296,10 @@ pub trait ToolsContract:
         let royalties = BigUint::from(SWORD_NFT_ROYALTIES);
 
         // Get the attributes
-        let attributes = self.get_nft_attributes(&sword);
+        let attributes = self.get_tool_nft_attributes(&sword);
 
         // Get the URIs
-        let uris = self.get_nft_asset_uris(&sword);
+        let uris = self.get_tool_nft_asset_uris(&sword);
 
         // Get the attributes hash
         let attributes_sha256 = self.crypto().sha256(&attributes);
@@ -314,19 +322,210 @@ pub trait ToolsContract:
     }
 
 
+    // Cancel functionality
+
+    /// Cancels a pending tool mint of the caller and refunds the escrowed payment minus the cancel fee
+    ///
+    /// # Arguments
+    /// * `tool_type` - Tool type of the pending mint (1 Shield, 2 Sword)
+    /// * `index` - Index of the pending mint in the caller's list for the tool type, starting from 0
+    #[endpoint(cancelPendingMint)]
+    fn cancel_pending_mint(&self, tool_type: u8, index: usize) {
+        let user = self.blockchain().get_caller();
+
+        self.migrate_legacy_tools_to_mint(tool_type, &user);
+        let mut tools_to_mint = self.get_tools_to_mint(tool_type);
+
+        let mut user_tools = tools_to_mint.get(&user).unwrap_or_default();
+        let tools_pending_count = user_tools.len();
+        require!(index < tools_pending_count, "No pending tool mint at index {}. {} tool(s) pending.", index, tools_pending_count);
+
+        // Remove the pending mint keeping the order of the remaining ones
+        let pending_mint = user_tools.get(index).clone();
+        require!(!pending_mint.payments.is_empty(), "Tool at index {} was paid before the escrow and cannot be cancelled.", index);
+        user_tools.remove(index);
+        if user_tools.is_empty() {
+            tools_to_mint.remove(&user);
+        } else {
+            tools_to_mint.insert(user.clone(), user_tools);
+        }
+
+        // Refund the escrowed payment minus the cancel fee
+        self.refund_pending_mint_payments(&user, &pending_mint, self.get_cancel_mint_fee());
+    }
+
+    /// Get the pending mints of the tool type
+    fn get_tools_to_mint(&self, tool_type: u8) -> MapMapper<ManagedAddress, ManagedVec<PendingMint<Self::Api>>> {
+        if tool_type == Tool::new_shield().tool_type { self.shields_to_mint() }
+        else if tool_type == Tool::new_sword().tool_type { self.swords_to_mint() }
+        else { sc_panic!("Unknown tool type {}.", tool_type) }
+    }
+
+    /// Get the pending mints of the tool type queued before the escrow
+    fn get_legacy_tools_to_mint(&self, tool_type: u8) -> MapMapper<ManagedAddress, ManagedVec<u64>> {
+        if tool_type == Tool::new_shield().tool_type { self.legacy_shields_to_mint() }
+        else if tool_type == Tool::new_sword().tool_type { self.legacy_swords_to_mint() }
+        else { sc_panic!("Unknown tool type {}.", tool_type) }
+    }
+
+    /// Move the tool mints queued before the escrow to the tools to mint of the user
+    /// Their payment was burned on mint, so they have no escrowed payments
+    fn migrate_legacy_tools_to_mint(&self, tool_type: u8, user: &ManagedAddress) {
+        let legacy_user_tools = match self.get_legacy_tools_to_mint(tool_type).remove(user) {
+            Some(legacy_user_tools) => legacy_user_tools,
+            None => return,
+        };
+
+        let mut tools_to_mint = self.get_tools_to_mint(tool_type);
+        let mut user_tools = tools_to_mint.get(user).unwrap_or_default();
+        for start_timestamp in legacy_user_tools.iter() {
+            user_tools.push(PendingMint { start_timestamp, payments: ManagedVec::new() });
+        }
+        tools_to_mint.insert(user.clone(), user_tools);
+    }
+
+
+    // Speed-up functionality
+
+    /// Speeds up a pending tool mint of the caller by paying the speed-up price for each second saved
+    /// The payment is burned, or sent to the treasury if one is set
+    ///
+    /// # Arguments
+    /// * `tool_type` - Tool type of the pending mint (1 Shield, 2 Sword)
+    /// * `index` - Index of the pending mint in the caller's list for the tool type, starting from 0
+    /// * `seconds` - Seconds to cut from the remaining wait optional, if not specified the mint is finished instantly
+    #[payable]
+    #[endpoint(speedUpPendingMint)]
+    fn speed_up_pending_mint(&self, tool_type: u8, index: usize, seconds: OptionalValue<u64>) {
+        require!(!self.speed_up_price().is_empty(), "Speed-up price not set.");
+
+        let user = self.blockchain().get_caller();
+
+        self.migrate_legacy_tools_to_mint(tool_type, &user);
+        let mut tools_to_mint = self.get_tools_to_mint(tool_type);
+        let mut user_tools = tools_to_mint.get(&user).unwrap_or_default();
+        let tools_pending_count = user_tools.len();
+        require!(index < tools_pending_count, "No pending tool mint at index {}. {} tool(s) pending.", index, tools_pending_count);
+
+        // Get the remaining wait of the pending mint
+        let mut pending_mint = user_tools.get(index).clone();
+        let remaining_seconds = self.get_tool_mint_remaining_seconds(tool_type, &pending_mint);
+        require!(remaining_seconds > 0, "Tool at index {} is ready to be claimed.", index);
+
+        // Pay for the seconds cut from the remaining wait
+        let seconds_saved = self.pay_speed_up(remaining_seconds, seconds);
+
+        // Move the mint start timestamp back by the seconds saved
+        pending_mint.start_timestamp -= seconds_saved;
+        let _ = user_tools.set(index, pending_mint);
+        tools_to_mint.insert(user, user_tools);
+
+    }
+
+    /// Returns the cost to finish a pending tool mint of the user instantly, as the speed-up token and amount
+    ///
+    /// # Arguments
+    /// * `user` - User address
+    /// * `tool_type` - Tool type of the pending mint (1 Shield, 2 Sword)
+    /// * `index` - Index of the pending mint in the user's list for the tool type, starting from 0
+    #[view(getSpeedUpCost)]
+    fn get_speed_up_cost(&self, user: ManagedAddress, tool_type: u8, index: usize) -> EsdtTokenPayment {
+        require!(!self.speed_up_price().is_empty(), "Speed-up price not set.");
+
+        let user_tools = self.get_tools_to_mint(tool_type).get(&user).unwrap_or_default();
+        let tools_pending_count = user_tools.len();
+        require!(index < tools_pending_count, "No pending tool mint at index {}. {} tool(s) pending.", index, tools_pending_count);
+
+        let remaining_seconds = self.get_tool_mint_remaining_seconds(tool_type, &user_tools.get(index));
+
+        self.get_speed_up_payment(remaining_seconds)
+    }
+
+    /// Get the seconds left before a pending tool mint can be claimed
+    fn get_tool_mint_remaining_seconds(&self, tool_type: u8, pending_mint: &PendingMint<Self::Api>) -> u64 {
+        let mint_seconds_mapper = if tool_type == Tool::new_shield().tool_type { self.mint_shield_seconds() }
+            else { self.mint_sword_seconds() };
+        let mint_seconds_default = if tool_type == Tool::new_shield().tool_type { MINT_SHIELD_SECONDS_DEFAULT }
+            else { MINT_SWORD_SECONDS_DEFAULT };
+        let mint_seconds = if mint_seconds_mapper.is_empty() { mint_seconds_default }
+            else { mint_seconds_mapper.get() };
+
+        (pending_mint.start_timestamp + mint_seconds).saturating_sub(self.blockchain().get_block_timestamp())
+    }
+
+
+    // Salvage functionality
+
+    /// Salvages a tool NFT by burning it and returning a share of its ORE and GOLD cost
+    ///
+    /// # Arguments
+    /// * `receiver_address` - Receiver address optional, if not specified the caller address will be used
+    #[payable]
+    #[endpoint(salvageTool)]
+    fn salvage_tool(&self, receiver_address: OptionalValue<ManagedAddress>) {
+        self.require_tools_collection();
+
+        let payment = self.call_value().single_esdt();
+        let tool_nonce = payment.token_nonce;
+        self.tools_nft_collection().require_same_token(&payment.token_identifier);
+
+        // Decode the tool type from the NFT attributes
+        let tool_nft_data = self.blockchain().get_esdt_token_data(
+            &self.blockchain().get_sc_address(),
+            &payment.token_identifier,
+            tool_nonce);
+        let tool = self.decode_tool(tool_nft_data.attributes);
+
+        // Get the ORE and GOLD returned for the tool type
+        let (ore_quantity, gold_quantity) = if tool.is_shield() { (MINT_SHIELD_ORE_QUANTITY, 0u64) }
+            else if tool.is_sword() { (MINT_SWORD_ORE_QUANTITY, MINT_SWORD_GOLD_QUANTITY) }
+            else { sc_panic!("Unknown tool type {}.", tool.tool_type) };
+
+        // Rounded up, so a share of a single GOLD is not lost
+        let salvage_share = self.get_salvage_share();
+        let ore_amount = BigUint::from((ore_quantity * salvage_share).div_ceil(MAX_SALVAGE_SHARE));
+        let gold_amount = BigUint::from((gold_quantity * salvage_share).div_ceil(MAX_SALVAGE_SHARE));
+
+        // Take the returned tokens from the salvage reserve
+        let mut payments = ManagedVec::new();
+        if ore_amount > 0 { payments.push(self.take_from_reserve(self.salvage_reserve(), "salvage", ORE_TICKER, ore_amount)); }
+        if gold_amount > 0 { payments.push(self.take_from_reserve(self.salvage_reserve(), "salvage", GOLD_TICKER, gold_amount)); }
+
+        // Burn the tool NFT
+        self.send().esdt_local_burn(&payment.token_identifier, tool_nonce, &payment.amount);
+
+        // Determine the receiver address if one is specified
+        let user = match receiver_address {
+            OptionalValue::Some(address) => address,
+            OptionalValue::None => self.blockchain().get_caller(),
+        };
+
+        // Send the salvaged tokens to the user
+        if !payments.is_empty() {
+            self.send().direct_multi(&user, &payments);
+        }
+    }
+
+    /// Get the salvage share in basis points
+    fn get_salvage_share(&self) -> u64 {
+        if self.salvage_share().is_empty() { SALVAGE_SHARE_DEFAULT }
+        else { self.salvage_share().get() }
+    }
+
+
     // Common functions
 
     /// Gets the attributes for the NFT
     /// Encode nft attributes in the format: metadata:IPFS_CID/{filename}.json;tags:{tag(s)}{PREFIX}{tool_type}:{attack}:{defence}
     /// Ex: metadata:IPFS_CID/shield.json;tags:tool,shield;t:1:0:1
     /// Ex: metadata:IPFS_CID/sword.json;tags:tool,sword;t:2:1:0
-    fn get_nft_attributes(&self, tool: &Tool) -> ManagedBuffer {
+    fn get_tool_nft_attributes(&self, tool: &Tool) -> ManagedBuffer {
         let nft_attributes = ManagedBuffer::from(
             sc_format!("metadata:{}/{}.{};tags:{}{}{}:{}:{}",
             ManagedBuffer::from(IPFS_TOOLS_CID),
-            self.get_asset_filename(tool),
+            self.get_tool_asset_filename(tool),
             ManagedBuffer::from(NFT_METADATA_FILE_EXTENSION), 
-            self.get_asset_tags(tool),
+            self.get_tool_asset_tags(tool),
             ManagedBuffer::from(NFT_TOOL_ATTRIBUTES_PREFIX), 
             tool.tool_type, 
             tool.attack, 
@@ -335,12 +534,12 @@ pub trait ToolsContract:
     }
 
     /// Get the URIs for the NFT assets (image, metadata)
-    fn get_nft_asset_uris(&self, tool: &Tool) -> ManagedVec<ManagedBuffer> {
+    fn get_tool_nft_asset_uris(&self, tool: &Tool) -> ManagedVec<ManagedBuffer> {
         // Get the base filename
         let asset_base_filename = 
             sc_format!("https://{}.ipfs.w3s.link/{}", 
                 ManagedBuffer::from(IPFS_TOOLS_CID), 
-                self.get_asset_filename(tool));
+                self.get_tool_asset_filename(tool));
         // Get the image and metadata URIs by adding the file extension
         let asset_image = sc_format!("{}.{}", asset_base_filename, ManagedBuffer::from(NFT_IMAGE_FILE_EXTENSION));
         let asset_metadata = sc_format!("{}.{}", asset_base_filename, ManagedBuffer::from(NFT_METADATA_FILE_EXTENSION));
@@ -355,14 +554,14 @@ pub trait ToolsContract:
 
 
     /// Get the asset filename based on the tool
-    fn get_asset_filename(&self, tool: &Tool) -> ManagedBuffer {
+    fn get_tool_asset_filename(&self, tool: &Tool) -> ManagedBuffer {
         if tool.is_shield() { return ManagedBuffer::from(SHIELD_FILE_NAME) };
         if tool.is_sword() { return ManagedBuffer::from(SWORD_FILE_NAME) };
         sc_panic!("Unknown tool type {}.", tool.tool_type);
     }
 
     /// Get the asset tags based on the tool
-    fn get_asset_tags(&self, tool: &Tool) -> ManagedBuffer {
+    fn get_tool_asset_tags(&self, tool: &Tool) -> ManagedBuffer {
         if tool.is_shield() { return ManagedBuffer::from(SHIELD_NFT_TAGS) };
         if tool.is_sword() { return ManagedBuffer::from(SWORD_NFT_TAGS) };
         sc_panic!("Unknown tool type {}.", tool.tool_type);
@@ -1,5 +1,7 @@
 use multiversx_sc::imports::*;
 
+use game_common_module::data::PendingMint;
+
 
 #[multiversx_sc::module]
 pub trait StorageModule {
@@ -9,15 +11,25 @@ pub trait StorageModule {
     #[storage_mapper("tools_nft_collection")]
     fn tools_nft_collection(&self) -> NonFungibleTokenMapper;
 
-    /// Shields to mint for each user
+    /// Shields to mint for each user, with the payment held in escrow until claim
     #[view(getShieldsToMint)]
-    #[storage_mapper("shieldsToMint")]
-    fn shields_to_mint(&self) -> MapMapper<ManagedAddress, ManagedVec<u64>>;
+    #[storage_mapper("pendingShieldMints")]
+    fn shields_to_mint(&self) -> MapMapper<ManagedAddress, ManagedVec<PendingMint<Self::Api>>>;
 
-    /// Swords to mint for each user
+    /// Swords to mint for each user, with the payment held in escrow until claim
     #[view(getSwordsToMint)]
+    #[storage_mapper("pendingSwordMints")]
+    fn swords_to_mint(&self) -> MapMapper<ManagedAddress, ManagedVec<PendingMint<Self::Api>>>;
+
+    /// Shield mint start timestamps queued before the escrow, moved to the shields to mint on the next user action
+    #[view(getLegacyShieldsToMint)]
+    #[storage_mapper("shieldsToMint")]
+    fn legacy_shields_to_mint(&self) -> MapMapper<ManagedAddress, ManagedVec<u64>>;
+
+    /// Sword mint start timestamps queued before the escrow, moved to the swords to mint on the next user action
+    #[view(getLegacySwordsToMint)]
     #[storage_mapper("swordsToMint")]
-    fn swords_to_mint(&self) -> MapMapper<ManagedAddress, ManagedVec<u64>>;
+    fn legacy_swords_to_mint(&self) -> MapMapper<ManagedAddress, ManagedVec<u64>>;
 
     /// Time in seconds to mint a shield
     #[view(getMintShieldSeconds)]
@@ -29,6 +41,16 @@ pub trait StorageModule {
     #[storage_mapper("mintSwordSeconds")]
     fn mint_sword_seconds(&self) -> SingleValueMapper<u64>;
 
+    /// Share of the tool ORE and GOLD cost returned when salvaging a tool, in basis points
+    #[view(getSalvageShare)]
+    #[storage_mapper("salvageShare")]
+    fn salvage_share(&self) -> SingleValueMapper<u64>;
+
+    /// ORE and GOLD reserve used to pay the salvaged tools
+    #[view(getSalvageReserve)]
+    #[storage_mapper("salvageReserve")]
+    fn salvage_reserve(&self) -> MapMapper<TokenIdentifier, BigUint>;
+
     /// Last minted NFT nonce
     #[view(getLastMintedNftNonce)]
     #[storage_mapper("lastMintedNftNonce")]
@@ -8,3 +8,18 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/tools_contract.scen.json");
 }
+
+#[test]
+fn legacy_tool_mints_go() {
+    world().run("scenarios/legacy_tool_mints.scen.json");
+}
+
+#[test]
+fn pending_mint_escrow_go() {
+    world().run("scenarios/pending_mint_escrow.scen.json");
+}
+
+#[test]
+fn salvage_tool_go() {
+    world().run("scenarios/salvage_tool.scen.json");
+}
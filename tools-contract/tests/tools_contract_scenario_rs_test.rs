@@ -12,3 +12,18 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/tools_contract.scen.json");
 }
+
+#[test]
+fn legacy_tool_mints_rs() {
+    world().run("scenarios/legacy_tool_mints.scen.json");
+}
+
+#[test]
+fn pending_mint_escrow_rs() {
+    world().run("scenarios/pending_mint_escrow.scen.json");
+}
+
+#[test]
+fn salvage_tool_rs() {
+    world().run("scenarios/salvage_tool.scen.json");
+}
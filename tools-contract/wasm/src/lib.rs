@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           13
+// Endpoints:                           29
 // Async Callback:                       1
-// Total number of exported functions:  16
+// Total number of exported functions:  32
 
 #![no_std]
 
@@ -24,15 +24,31 @@ multiversx_sc_wasm_adapter::endpoints! {
         claimShield => claim_shield
         mintSword => mint_sword
         claimSword => claim_sword
+        cancelPendingMint => cancel_pending_mint
+        speedUpPendingMint => speed_up_pending_mint
+        getSpeedUpCost => get_speed_up_cost
+        salvageTool => salvage_tool
         getToolsNftCollection => tools_nft_collection
         getShieldsToMint => shields_to_mint
         getSwordsToMint => swords_to_mint
+        getLegacyShieldsToMint => legacy_shields_to_mint
+        getLegacySwordsToMint => legacy_swords_to_mint
         getMintShieldSeconds => mint_shield_seconds
         getMintSwordSeconds => mint_sword_seconds
+        getSalvageShare => salvage_share
+        getSalvageReserve => salvage_reserve
         getLastMintedNftNonce => last_minted_nft_nonce
         registerToolsCollection => register_tools_collection
         setMintShieldSeconds => set_mint_shield_seconds
         setMintSwordSeconds => set_mint_sword_seconds
+        setSalvageShare => set_salvage_share
+        fundSalvageReserve => fund_salvage_reserve
+        setCancelMintFee => set_cancel_mint_fee
+        setSpeedUpPrice => set_speed_up_price
+        setSpeedUpTreasury => set_speed_up_treasury
+        getCancelMintFee => cancel_mint_fee
+        getSpeedUpPrice => speed_up_price
+        getSpeedUpTreasury => speed_up_treasury
     )
 }
 